@@ -0,0 +1,21 @@
+use aoc_common::Answer;
+
+pub fn part1(input: &str) -> Answer {
+    let mut measurements: Vec<u16> = Vec::new();
+
+    for line in input.lines() {
+        let depth: u16 = line.parse().unwrap();
+        measurements.push(depth);
+    }
+
+    let mut result = 0;
+    let mut last_measurement = *measurements.first().unwrap();
+    for &current_measurement in &measurements[1..measurements.len()] {
+        if current_measurement > last_measurement {
+            result += 1;
+        }
+        last_measurement = current_measurement;
+    }
+
+    result.into()
+}
@@ -0,0 +1,25 @@
+use aoc_common::Answer;
+
+const SLIDING_WINDOW_SIZE: usize = 3;
+
+pub fn part2(input: &str) -> Answer {
+    let mut measurements: Vec<u16> = Vec::new();
+
+    for line in input.lines() {
+        let depth: u16 = line.parse().unwrap();
+        measurements.push(depth);
+    }
+
+    // It is actually possible to do the computation directly in the parsing loop, though I'm not sure it's possible to use windows
+    let mut result = 0;
+    let mut windows_iter = measurements.windows(SLIDING_WINDOW_SIZE);
+    let mut last_window = windows_iter.next().unwrap();
+    for current_window in windows_iter {
+        if current_window.iter().sum::<u16>() > last_window.iter().sum::<u16>() {
+            result += 1;
+        }
+        last_window = current_window;
+    }
+
+    result.into()
+}
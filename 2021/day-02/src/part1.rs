@@ -0,0 +1,21 @@
+use aoc_common::Answer;
+
+pub fn part1(input: &str) -> Answer {
+    let mut total_horizontal_movement = 0;
+    let mut depth = 0;
+
+    for line in input.lines() {
+        let (direction, distance_str) = line.split_once(" ").unwrap();
+        let distance: i16 = distance_str.parse().unwrap();
+        match direction {
+            "forward" => total_horizontal_movement += distance,
+            "down" => depth += distance,
+            "up" => depth -= distance,
+            _ => unreachable!("The input is unknown")
+        }
+    }
+
+    let result: isize = depth as isize * total_horizontal_movement as isize;
+
+    result.into()
+}
@@ -0,0 +1,25 @@
+use aoc_common::Answer;
+
+pub fn part2(input: &str) -> Answer {
+    let mut total_horizontal_movement = 0;
+    let mut depth = 0;
+    let mut aim = 0;
+
+    for line in input.lines() {
+        let (direction, distance_str) = line.split_once(" ").unwrap();
+        let distance: isize = distance_str.parse().unwrap();
+        match direction {
+            "forward" => {
+                total_horizontal_movement += distance;
+                depth += aim * distance;
+            },
+            "down" => aim += distance,
+            "up" => aim -= distance,
+            _ => unreachable!("The input is unknown")
+        }
+    }
+
+    let result: isize = depth * total_horizontal_movement;
+
+    result.into()
+}
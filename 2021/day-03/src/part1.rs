@@ -0,0 +1,34 @@
+use aoc_common::Answer;
+
+const BINARY_SIZE: usize = 12;
+
+pub fn part1(input: &str) -> Answer {
+        // (n, m) where n is the number of 0 bits, m the number of 1 bits
+        let mut bits_count = [(0, 0); BINARY_SIZE];
+
+    for line in input.lines() {
+        assert!(line.len() == BINARY_SIZE);
+        for (i, c) in line.char_indices() {
+            match c {
+                '0' => bits_count[i].0 += 1,
+                '1' => bits_count[i].1 += 1,
+                _ => unreachable!()
+            }
+        }
+    }
+
+    let mut gamma = 0;
+    for pair in bits_count.iter() {
+        gamma <<= 1;
+        if pair.1 > pair.0 {
+            gamma += 1;
+        }
+    }
+    let epsilon = u64::pow(2, BINARY_SIZE as u32) -1 - gamma;
+
+    println!("Epsilon {epsilon} and gamma {gamma}");
+
+    let result: u64 = gamma * epsilon;
+
+    result.into()
+}
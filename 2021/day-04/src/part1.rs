@@ -0,0 +1,76 @@
+use std::cmp::max;
+use std::collections::HashMap;
+use aoc_common::Answer;
+
+#[derive(Clone)]
+struct Board {
+    content: HashMap<usize, (usize, usize)>,
+    rows: Vec<usize>, // rows[i] is the number of unchecked numbers in row i
+    cols: Vec<usize>
+} 
+
+impl Board {
+    fn new() -> Self {
+        Self {
+            content: HashMap::new(),
+            rows: Vec::new(),
+            cols: Vec::new(),
+        }
+    }
+}
+
+pub fn part1(input: &str) -> Answer {
+    let mut drawn_numbers: Vec<usize> = Vec::new();
+    let mut boards: Vec<Board> = Vec::new();
+    let mut current_board: Board = Board::new();
+
+    let mut nb_columns: usize = 0;
+    let mut nb_rows: usize = 0;
+
+    // parsing
+    let mut row = 0;
+    for (line_number, line) in (1..).zip(input.lines()) {
+        if line_number == 1 {
+            drawn_numbers = line.split(',').map(|num| num.parse().unwrap()).collect();
+        } else if line.is_empty() {
+            if line_number != 2 {
+                boards.push(current_board.clone());
+            }
+            current_board = Board::new();
+            row = 0;
+        } else {
+            for (column, val) in line.split_whitespace().map(|v| v.parse().unwrap()).enumerate() {
+                current_board.content.insert(val, (row, column));
+                nb_rows = max(nb_rows, row+1);
+                nb_columns = max(nb_columns, column+1);
+            }
+            row += 1;
+        }
+    }
+    boards.push(current_board);
+
+    for board in &mut boards {
+        board.rows = vec![nb_columns;nb_rows];
+        board.cols = vec![nb_rows;nb_columns];
+    }
+
+    // computation
+    let mut result: usize = 0;
+
+    'a: for num in drawn_numbers {
+        for board in &mut boards {
+            if board.content.contains_key(&num) {
+                let (row, col) = *board.content.get(&num).unwrap();
+                board.content.remove(&num);
+                *board.rows.get_mut(row).unwrap() -= 1;
+                *board.cols.get_mut(col).unwrap() -= 1;
+                if *board.rows.get(row).unwrap() == 0 || *board.cols.get(col).unwrap() == 0 {
+                    result = board.content.keys().sum::<usize>() * num;
+                    break 'a;
+                }
+            }
+        }
+    }
+
+    result.into()
+}
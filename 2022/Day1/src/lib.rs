@@ -0,0 +1,19 @@
+use std::cmp;
+use aoc_common::Answer;
+
+pub fn part1(input: &str) -> Answer {
+    let lines = input.split("\n");
+
+    let mut max_cal = 0;
+    let mut cal = 0;
+    for line in lines {
+        if line.is_empty() {
+            max_cal = cmp::max(max_cal, cal);
+            cal = 0;
+        } else {
+            let item_cal : i32 = line.parse().unwrap();
+            cal += item_cal;
+        }
+    }
+    max_cal.into()
+}
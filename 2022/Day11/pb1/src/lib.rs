@@ -0,0 +1,128 @@
+use std::collections::HashMap;
+use aoc_common::Answer;
+
+const LEFT: char = 'L';
+const RIGHT: char = 'R';
+
+const NODE_SIZE: usize = 3;
+
+struct Map {
+    instructions: Vec<char>,
+    nodes: HashMap<String, (String, String)>,
+}
+
+impl Map {
+
+    fn new() -> Self {
+        Self {
+            instructions: Vec::new(),
+            nodes: HashMap::new(),
+        }
+    }
+
+    fn add_node(&mut self, c1: String, c2: String, c3: String) {
+        self.nodes.insert(c1, (c2, c3));
+    }
+
+}
+
+pub fn part1(input: &str) -> Answer {
+    let mut desert_map = Map::new();
+    let mut starting_points = Vec::new();
+    let mut ending_points = Vec::new();
+
+    // Parsing
+    let mut lines_iter = input.lines();
+
+    // Get the instructions from line 1
+    let first_line = lines_iter.next().unwrap();
+    desert_map.instructions = first_line.chars().collect();
+
+    lines_iter.next();
+
+    // Get the nodes from the rest of the input
+    for text in lines_iter {
+
+        let (aaa, bbbccc) = text.split_once(" = (").unwrap();
+        let (bbb, ccc) = bbbccc.split_once(", ").unwrap();
+
+        match aaa.chars().nth(NODE_SIZE-1).unwrap() {
+            'A' => starting_points.push(aaa.to_string()),
+            'Z' => ending_points.push(aaa.to_string()),
+            _ => (),
+        }
+
+        //println!("{a} -> ({b}, {c})");
+        desert_map.add_node(aaa.to_string(), bbb.to_string(), ccc[0..3].to_string());
+    }
+
+    // Computation
+    let mut nb_steps = 0;
+    let mut current_states = starting_points.clone();
+
+    //let nb_instructions = desert_map.instructions.len();
+
+    let mut heads_that_found_z = Vec::new();
+    let mut instructions_to_z = Vec::new();
+
+    'a: while !current_states.iter().all(|x| ending_points.contains(x)) {
+
+        for &instruction in desert_map.instructions.iter() {
+
+            nb_steps += 1;
+
+            for (i, current_state) in current_states.iter_mut().enumerate() {
+                let (left, right) = desert_map.nodes.get(current_state).unwrap().clone();
+                
+                if instruction == LEFT {
+                    //println!("{current_state} -> {left}");
+                    *current_state = left;
+                } else if instruction == RIGHT {
+                    //println!("{current_state} -> {right}");
+                    *current_state = right;
+                } else {
+                    unreachable!();
+                }
+
+                if current_state.chars().nth(2).unwrap() == 'Z' && !heads_that_found_z.contains(&i) {
+                    println!("{}", nb_steps);
+                    heads_that_found_z.push(i);
+                    instructions_to_z.push(nb_steps);
+                    if heads_that_found_z.len() == starting_points.len() {
+                        break 'a;
+                    }
+                }
+            }
+        }
+    }
+
+    let mut lcm_val = 1;
+    for n in instructions_to_z {
+        lcm_val = lcm(lcm_val, n);
+    }
+
+    lcm_val.into()
+}
+
+
+fn lcm(first: usize, second: usize) -> usize {
+    first * second / gcd(first, second)
+}
+
+fn gcd(first: usize, second: usize) -> usize {
+    let mut max = first;
+    let mut min = second;
+    if min > max {
+        std::mem::swap(&mut max, &mut min);
+    }
+
+    loop {
+        let res = max % min;
+        if res == 0 {
+            return min;
+        }
+
+        max = min;
+        min = res;
+    }
+}
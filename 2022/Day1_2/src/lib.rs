@@ -0,0 +1,26 @@
+use aoc_common::Answer;
+
+// Update tab to get the sorted array of the top three values from n and tab
+fn top_three(n : i32, mut v : Vec<i32>) -> Vec<i32> {
+    v.push(n);
+    v.sort();
+    v.remove(0);
+    v
+}
+
+pub fn part2(input: &str) -> Answer {
+    let lines = input.split("\n");
+
+    let mut max_cal = vec!(0, 0, 0);
+    let mut cal = 0;
+    for line in lines {
+        if line.is_empty() {
+            max_cal = top_three(cal, max_cal);
+            cal = 0;
+        } else {
+            let item_cal : i32 = line.parse().unwrap();
+            cal += item_cal;
+        }
+    }
+    max_cal.iter().sum::<i32>().into()
+}
@@ -0,0 +1,107 @@
+use aoc_common::Answer;
+
+enum Moves {
+    Rock,
+    Paper,
+    Scissors
+}
+
+fn get_their_move(mv : &str) -> Moves {
+    get_move(mv, "A", "B", "C")
+}
+
+fn get_my_move(their_move : &Moves, mv : &str) -> Moves {
+    if mv == "Y" {
+        match their_move {
+            Moves::Rock => Moves::Rock,
+            Moves::Paper => Moves::Paper,
+            Moves::Scissors => Moves::Scissors,
+        }
+    } else if mv == "X" {
+        match their_move {
+            Moves::Rock => Moves::Scissors,
+            Moves::Paper => Moves::Rock,
+            Moves::Scissors => Moves::Paper,
+        }
+    } else if mv == "Z" {
+        match their_move {
+            Moves::Rock => Moves::Paper,
+            Moves::Paper => Moves::Scissors,
+            Moves::Scissors => Moves::Rock,
+        }
+    } else {
+        panic!();
+    }
+}
+
+fn get_move(mv : &str, rock : &str, paper : &str, scissors : &str) 
+        -> Moves {
+    if mv == rock {
+        Moves::Rock
+    } else if mv == paper {
+        Moves::Paper
+    } else if mv == scissors {
+        Moves::Scissors
+    } else {
+        panic!();
+    }
+}
+
+fn calculate_points(my_move : Moves, their_move : Moves) -> i32{
+    move_points(&my_move) + matchup_points(&my_move, &their_move)
+}
+
+fn move_points(mv : &Moves) -> i32 {
+    match mv {
+        Moves::Rock => 1,
+        Moves::Paper => 2,
+        Moves::Scissors => 3,
+    }
+}
+
+fn matchup_points(my_move : &Moves, their_move : &Moves) -> i32 {
+    let m1 = move_points(my_move);
+    let m2 = move_points(their_move);
+    if m1 == m2 {
+        3
+    } else {
+        if m1 < m2 {
+            if m1 == 1 && m2 == 3 {
+                6
+            } else {
+                0
+            }
+        } else {
+            if m1 == 3 && m2 == 1 {
+                0
+            } else {
+                6
+            }
+        }
+    }
+
+}
+
+pub fn part2(input: &str) -> Answer {
+    let mut score = 0;
+    
+    for text in input.lines() {
+        if !text.is_empty() {
+
+            let mut their_move = Moves::Rock;
+            let mut my_move = Moves::Rock;
+
+            let mut v = text.split(" ");
+            if let Some(first_move) = v.next() {
+                their_move = get_their_move(first_move);
+            }
+            if let Some(second_move) = v.next() {
+                my_move = get_my_move(&their_move, second_move);
+            }
+
+            score += calculate_points(my_move, their_move);
+        }
+    }
+    
+    score.into()
+}
@@ -0,0 +1,48 @@
+use aoc_common::Answer;
+
+// Get priority of a letter
+fn priority(letter: char) -> u32 {
+    let mut n = letter as u32;
+    //println!("{letter}");
+    if n <= ('z' as u32) && n >= ('a' as u32) {
+        n = n - ('a' as u32) + 1;
+    } else if n <= ('Z' as u32) && n >= ('A' as u32) {
+        n = n - ('A' as u32) + 27;
+    } else {
+        panic!("Error");
+    }
+    n
+}
+
+// Get same letter in two strings/arrays
+fn same_letter(s1: &str, s2: &str) -> char {
+    let mut c = ' ';
+    for letter in s1.chars() {
+        if s2.contains(letter) {
+            c = letter;
+        }
+    }
+    c
+}
+
+// Split string in two
+fn split_string(s: &str) -> (&str, &str) {
+    let n = s.len();
+    (&s[0..n/2], &s[n/2..n])
+}
+
+pub fn part1(input: &str) -> Answer {
+    let mut score: u32 = 0;
+    
+    for text in input.lines() {
+        if !text.is_empty() {
+
+            let (s1, s2) = split_string(text);
+            let c = same_letter(s1, s2);
+            let priority = priority(c);
+            score += priority;
+
+        }
+    }
+    score.into()
+}
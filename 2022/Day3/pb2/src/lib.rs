@@ -0,0 +1,52 @@
+use aoc_common::Answer;
+
+// Get priority of a letter
+fn priority(letter: char) -> u32 {
+    let mut n = letter as u32;
+    //println!("{letter}");
+    if n <= ('z' as u32) && n >= ('a' as u32) {
+        n = n - ('a' as u32) + 1;
+    } else if n <= ('Z' as u32) && n >= ('A' as u32) {
+        n = n - ('A' as u32) + 27;
+    } else {
+        panic!("Error");
+    }
+    n
+}
+
+// Get same letter in three strings/arrays
+fn same_letter(s1: &str, s2: &str, s3: &str) -> char {
+    let mut c = ' ';
+    for letter in s1.chars() {
+        if s2.contains(letter) && s3.contains(letter) {
+            c = letter;
+        }
+    }
+    c
+}
+
+/* // Split string in two
+fn split_string(s: &str) -> (&str, &str) {
+    let n = s.len();
+    return (&s[0..n/2], &s[n/2..n]);
+} */
+
+pub fn part2(input: &str) -> Answer {
+    let mut score: u32 = 0;
+    
+    let mut k = 0;
+    let mut s: Vec<String> = vec!["".to_string(), "".to_string(), "".to_string()];
+    for text in input.lines() {
+        if !text.is_empty() {
+            s[k] = text.to_string();
+            k += 1;
+            if k == 3 {
+                k = 0;
+                let c = same_letter(&s[0], &s[1], &s[2]);
+                let priority = priority(c);
+                score += priority;
+            }
+        }
+    }
+    score.into()
+}
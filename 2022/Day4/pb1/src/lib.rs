@@ -0,0 +1,25 @@
+use aoc_common::Answer;
+
+
+pub fn part1(input: &str) -> Answer {
+    let mut score: u32 = 0;
+    
+    for text in input.lines() {
+        if !text.is_empty() {
+
+            let mut split = text.split(",");
+            let elf1 = split.next().unwrap();
+            let elf2 = split.next().unwrap();
+            let mut split1 = elf1.split("-");
+            let mut split2 = elf2.split("-");
+            let x1: u32 = split1.next().unwrap().parse().unwrap();
+            let x2: u32 = split1.next().unwrap().parse().unwrap();
+            let y1: u32 = split2.next().unwrap().parse().unwrap();
+            let y2: u32 = split2.next().unwrap().parse().unwrap();
+            if (x1 >= y1 && x2 <= y2) || (x1 <= y1 && x2 >= y2){
+                score += 1;
+            }
+        }
+    }
+    score.into()
+}
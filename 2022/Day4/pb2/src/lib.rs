@@ -0,0 +1,35 @@
+use aoc_common::Answer;
+
+fn overlap(x1: u32, x2: u32, y1: u32, y2: u32) -> bool {
+    is_between(x1, y1, y2) 
+        || is_between(x2, y1, y2)
+        || is_between(y1, x1, x2)
+        || is_between(y2, x1, x2)
+}
+
+// if x is between y and z, with y <= z
+fn is_between(x: u32, y: u32, z: u32) -> bool {
+    x >= y && x <= z
+}
+
+pub fn part2(input: &str) -> Answer {
+    let mut score: u32 = 0;
+    
+    for text in input.lines() {
+        if !text.is_empty() {
+            let mut split = text.split(",");
+            let elf1 = split.next().unwrap();
+            let elf2 = split.next().unwrap();
+            let mut split1 = elf1.split("-");
+            let mut split2 = elf2.split("-");
+            let x1: u32 = split1.next().unwrap().parse().unwrap();
+            let x2: u32 = split1.next().unwrap().parse().unwrap();
+            let y1: u32 = split2.next().unwrap().parse().unwrap();
+            let y2: u32 = split2.next().unwrap().parse().unwrap();
+            if overlap(x1, x2, y1, y2) {
+                score += 1;
+            }
+        }
+    }
+    score.into()
+}
@@ -0,0 +1,82 @@
+use aoc_common::Answer;
+
+enum Etat {
+    PILES,
+    SPACE,
+    INSTRUCTS,
+}
+
+fn read_instruct(s: &str) -> (usize, usize, usize) {
+    let mut words = s.split(' ');
+    assert!(words.next().unwrap() == "move");
+    let nb = words.next().unwrap().parse().unwrap();
+    assert!(words.next().unwrap() == "from");
+    let source = words.next().unwrap().parse().unwrap();
+    assert!(words.next().unwrap() == "to");
+    let dest = words.next().unwrap().parse().unwrap();
+    (nb, source, dest)
+}
+
+pub fn part2(input: &str) -> Answer {
+    let mut etat = Etat::PILES;
+    let mut nb_piles = 0;
+
+    let mut piles_data: Vec<String> = Vec::new();
+    let mut piles: Vec<Vec<char>> = Vec::new();
+    
+    for text in input.lines() {
+        match etat {
+            Etat::PILES =>
+                if !text.contains('1') {
+                    piles_data.push(text.to_string());
+                } else {
+                    let indices = text.split(' ');
+                    for indice in indices {
+                        if let Ok(n) = indice.parse::<u32>() {
+                            nb_piles = n;
+                            assert!(n < 10 && n > 0);
+                        }
+                    }
+                    for _ in 0..nb_piles {
+                        piles.push(Vec::new());
+                    }
+                    etat = Etat::SPACE;
+                },
+            Etat::SPACE => 
+                {
+                    while let Some(line) = piles_data.pop() {
+                        
+                        let mut chars = line.chars();
+                        chars.next();
+                        for (k, char) in chars.enumerate() {
+                            if k % 4 == 0 && char != ' ' {
+                                piles.get_mut(k / 4).unwrap().push(char);
+                            }
+                        }                         
+                    }
+                    etat = Etat::INSTRUCTS;
+                }
+            Etat::INSTRUCTS => {
+                if text.split(' ').next() != Some("") {
+                    let (nb, source, dest) = read_instruct(text);
+                    let mut to_move: Vec<char> = Vec::new();
+                    for _ in 0..nb {
+                        to_move.push(piles.get_mut(source-1)
+                            .unwrap().pop().unwrap());
+                    }
+                    for _ in 0..nb {
+                        piles.get_mut(dest-1).unwrap()
+                            .push(to_move.pop().unwrap());
+                    }
+                }
+            },
+        }
+
+    }
+    let mut res = String::new();
+    for mut pile in piles {
+        res.push(pile.pop().unwrap());
+    }
+
+    res.into()
+}
@@ -0,0 +1,58 @@
+use aoc_common::Answer;
+
+struct EnsembleTrie {
+    size: usize,
+    letters: Vec<char>,
+    index: usize,
+}
+
+impl EnsembleTrie {
+    pub fn new(size: usize) -> EnsembleTrie {
+        EnsembleTrie { 
+            size,
+            letters: Vec::new(),
+            index: 0,
+        }
+    }
+
+    pub fn all_differents(&mut self, c: char) -> bool {
+        let index = self.next_index();
+        if self.letters.len() == index {
+            self.letters.push(' ');
+        }
+        self.letters[index] = c;
+        let mut letters: Vec<char> = Vec::new();
+        for letter in &self.letters {
+            if !letters.contains(letter) {
+                letters.push(*letter);
+            }
+        }
+        letters.len() == self.size
+    }
+
+    fn next_index(&mut self) -> usize {
+        let res = self.index;
+        self.index += 1;
+        if self.index == self.size {
+            self.index = 0;
+        }
+        res
+    }
+}
+
+pub fn part2(input: &str) -> Answer {
+    for text in input.lines() {
+        if !text.is_empty() {
+            let mut k: usize = 0;
+            let mut last_letters = EnsembleTrie::new(14);
+            for letter in text.chars() {
+                k += 1;
+                if last_letters.all_differents(letter) {
+                    return k.into();
+                }
+            }
+        }
+    }
+
+    unreachable!("no marker in the input")
+}
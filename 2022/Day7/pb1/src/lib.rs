@@ -0,0 +1,97 @@
+use std::path::PathBuf;
+use std::collections::HashMap;
+use aoc_common::Answer;
+
+struct Directory {
+    dirs: Vec<PathBuf>,
+    files: Vec<PathBuf>,
+}
+
+impl Directory {
+    pub fn new() -> Directory {
+        Directory { dirs: Vec::new(), files: Vec::new() }
+    }
+
+    pub fn add_dir(&mut self, dir: PathBuf) {
+        self.dirs.push(dir);
+    }
+
+    pub fn add_file(&mut self, file: PathBuf) {
+        self.files.push(file);
+    }
+}
+
+struct AFile {
+    size: u32,
+}
+
+impl AFile {
+    pub fn new(size: u32) -> AFile {
+        AFile { size }
+    }
+}
+
+pub fn part1(input: &str) -> Answer {
+    let mut directories: HashMap<PathBuf, Directory> = HashMap::new();
+    directories.insert(PathBuf::from("/"), Directory::new());
+    let mut files: HashMap<PathBuf, AFile> = HashMap::new();
+    let mut current_path: PathBuf = PathBuf::new();
+    
+    for text in input.lines() {
+        if !text.is_empty() {            
+            
+            let mut split = text.split(" ").map(String::from);
+            let first_word = split.next().unwrap();
+            
+            match first_word.as_str() {
+                "$" => {
+                    if split.next().unwrap() == "cd" {
+                        let current_directory = split.next().unwrap();
+                        if current_directory == ".." {
+                            current_path = current_path.parent().unwrap().to_path_buf();
+                        } else {
+                            current_path = current_path.join(current_directory);
+                        }
+                    }
+                },
+                "dir" => {
+                    let dir_name = split.next().unwrap();
+                    let curr_dir = directories.get_mut(&current_path).unwrap();
+                    curr_dir.add_dir(current_path.join(&dir_name));
+                    directories.insert(current_path.join(&dir_name), Directory::new());
+                },
+                num => {
+                    let file_name = split.next().unwrap();
+                    let curr_dir = directories.get_mut(&current_path).unwrap();
+                    curr_dir.add_file(current_path.join(&file_name));
+                    files.insert(current_path.join(&file_name), AFile::new(num.parse::<u32>().unwrap()));
+                },
+            }
+
+        }
+    }
+
+    let mut res = 0;
+
+    fn add_to_res(dir: &Directory, files: &HashMap<PathBuf, AFile>, directories: &HashMap<PathBuf, Directory>, res: &mut u32) -> u32 {
+        let mut size = 0;
+        for file_name in dir.files.iter() {
+            let file = files.get(file_name).unwrap();
+            size += file.size;
+        }
+        for dir_name in dir.dirs.iter() {
+            let sub_dir = directories.get(dir_name).unwrap();
+            size += add_to_res(sub_dir, files, directories, res);
+        }
+
+        if size <= 100000 {
+            *res += size
+        }
+
+        size
+    }
+
+    add_to_res(directories.get(&PathBuf::from("/")).unwrap(), &files, &directories, &mut res);
+
+    res.into()
+}
@@ -0,0 +1,55 @@
+use aoc_common::Answer;
+
+fn scenic_score(foret: &[Vec<i32>], i: usize, j: usize) -> u32{
+    score_direction(foret, i, j, 1, 0) 
+        * score_direction(foret, i, j, -1, 0)
+        * score_direction(foret, i, j, 0, 1)
+        * score_direction(foret, i, j, 0, -1)
+}
+
+fn score_direction(foret: &[Vec<i32>], x: usize, y: usize, dx: i32, dy: i32) 
+    -> u32
+{
+    let n = foret.len();
+    let mut i = x as i32;
+    let mut j = y as i32;
+    let mut stop = false; // quand on rencontre un arbre trop grand
+    let mut res = 0;
+    while !stop && in_range(i+ dx, j + dy, n as i32) {
+        i += dx;
+        j += dy;
+        stop = foret[x][y] <= foret[i as usize][j as usize];
+        res += 1;
+    }
+    res
+}
+
+fn in_range(i: i32, j: i32, n: i32) -> bool {
+    i < n && i >= 0 && j < n && j >= 0
+}
+
+pub fn part1(input: &str) -> Answer {
+    let mut foret: Vec<Vec<i32>> = Vec::new();
+    
+    for text in input.lines() {
+        if !text.is_empty() {            
+            let mut ligne: Vec<i32> = Vec::new();                    
+            for arbre in text.chars() {
+                ligne.push(arbre.to_digit(10).unwrap().try_into().unwrap());
+            }
+            foret.push(ligne);
+        }
+    }
+
+    let n = foret.len();
+
+    let mut max_scenic_score = 0;
+
+    for i in 0..n {
+        for j in 0..n {
+            max_scenic_score = core::cmp::max(max_scenic_score, scenic_score(&foret, i, j));
+        }
+    }
+
+    max_scenic_score.into()
+}
@@ -0,0 +1,136 @@
+use nom::IResult;
+use std::collections::HashSet;
+use aoc_common::{Answer, Direction, Point};
+
+// adjacent even if they are diagonal
+fn next_to(a: Point, b: Point) -> bool {
+    a.chebyshev(b) <= 1
+}
+
+struct HeadTail {
+    head: Point,
+    tail: Point,
+}
+
+impl HeadTail {
+    fn new(head: Point, tail: Point) -> HeadTail {
+        HeadTail { head, tail }
+    }
+
+    fn init() -> HeadTail {
+        HeadTail::new(Point::ORIGIN, Point::ORIGIN)
+    }
+
+    // Move the head in the given direction one step
+    // The tail will follow the head if it is too far away
+    fn move_head(&mut self, direction: Direction) {
+        // move the head in the given direction
+        self.head = self.head.step(direction);
+
+        // update the tail
+        if !next_to(self.head, self.tail) {
+            for d in [
+                Direction::North,
+                Direction::South,
+                Direction::West,
+                Direction::East,
+            ] {
+                if self.head == self.tail.step_n(d, 2) {
+                    self.tail = self.tail.step(d);
+                    assert!(next_to(self.head, self.tail));
+                    break;
+                } else {
+                    for perp in d.perpendicular() {
+                        if self.head == self.tail.step_n(d, 2).step(perp) {
+                            self.tail = self.tail.step(d).step(perp);
+                            assert!(next_to(self.head, self.tail));
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+struct Instruction {
+    direction: Direction,
+    distance: isize,
+}
+
+pub fn part1(input: &str) -> Answer {
+    let mut head_tail = HeadTail::init();
+
+    let mut instructions = Vec::new();
+
+    let mut visited = HashSet::new();
+    visited.insert(Point::ORIGIN);
+
+    for line in input.lines() {
+        let (_, instruction) = parse_instruction(line).unwrap();
+        instructions.push(instruction);
+    }
+
+    for instruction in instructions {
+        for _ in 0..instruction.distance {
+            head_tail.move_head(instruction.direction);
+            visited.insert(head_tail.tail);
+        }
+    }
+
+    let res = visited.len();
+
+    //_print_visited_as_2d(&visited);
+
+    res.into()
+}
+
+fn _print_visited_as_2d(visited: &HashSet<Point>) {
+    let min_x = visited.iter().map(|p| p.x).min().unwrap();
+    let max_x = visited.iter().map(|p| p.x).max().unwrap();
+    let min_y = visited.iter().map(|p| p.y).min().unwrap();
+    let max_y = visited.iter().map(|p| p.y).max().unwrap();
+    for y in min_y..=max_y {
+        for x in min_x..=max_x {
+            if Point::new(x, y) == Point::ORIGIN {
+                print!("s");
+            } else if visited.contains(&Point::new(x, y)) {
+                print!("#");
+            } else {
+                print!(".");
+            }
+        }
+        println!();
+    }
+}
+
+/*
+R 4
+U 4
+L 3
+D 1
+R 4
+D 1
+L 5
+R 2
+*/
+fn parse_instruction(input: &str) -> IResult<&str, Instruction> {
+    let (input, direction) = parse_direction(input)?;
+    let (input, _) = nom::character::complete::space1(input)?;
+    let (input, distance) = nom::character::complete::digit1(input)?;
+    let distance = distance.parse().unwrap();
+    Ok((
+        input,
+        Instruction {
+            direction,
+            distance,
+        },
+    ))
+}
+
+fn parse_direction(input: &str) -> IResult<&str, Direction> {
+    match input.chars().next() {
+        Some(c) => Ok((&input[1..], Direction::from_char(c).expect("Invalid direction"))),
+        None => panic!("Invalid direction"),
+    }
+}
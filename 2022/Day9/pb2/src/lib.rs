@@ -0,0 +1,147 @@
+use core::panic;
+use nom::IResult;
+use std::collections::HashSet;
+use aoc_common::{Answer, Direction, Point};
+
+// adjacent even if they are diagonal
+fn next_to(a: Point, b: Point) -> bool {
+    a.chebyshev(b) <= 1
+}
+
+const NB_TAIL: usize = 9;
+
+struct HeadTail {
+    points: [Point; NB_TAIL + 1],
+}
+
+impl HeadTail {
+    fn init() -> HeadTail {
+        HeadTail {
+            points: [Point::ORIGIN; NB_TAIL + 1],
+        }
+    }
+
+    fn last_tail(&self) -> Point {
+        self.points[NB_TAIL]
+    }
+
+    // Move the head in the given direction one step
+    fn move_head(&mut self, direction: Direction) {
+        // move the head in the given direction
+        self.points[0] = self.points[0].step(direction);
+
+        // update the tails
+        // TODO: the tails can move diagonally, but not the head
+        for i in 1..=NB_TAIL {
+            if !next_to(self.points[i - 1], self.points[i]) {
+                for d in [
+                    Direction::North,
+                    Direction::South,
+                    Direction::West,
+                    Direction::East,
+                ] {
+                    if self.points[i - 1] == self.points[i].step_n(d, 2) {
+                        self.points[i] = self.points[i].step(d);
+                        assert!(next_to(self.points[i - 1], self.points[i]));
+                        break;
+                    } else {
+                        for perp in d.perpendicular() {
+                            if self.points[i - 1]
+                                == self.points[i].step_n(d, 2).step(perp)
+                                || self.points[i - 1]
+                                    == self.points[i].step_n(d, 2).step_n(perp, 2)
+                            {
+                                self.points[i] =
+                                    self.points[i].step(d).step(perp);
+                                assert!(next_to(self.points[i - 1], self.points[i]));
+                                break;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+struct Instruction {
+    direction: Direction,
+    distance: isize,
+}
+
+pub fn part2(input: &str) -> Answer {
+    let mut head_tail = HeadTail::init();
+
+    let mut instructions = Vec::new();
+
+    let mut visited = HashSet::new();
+    visited.insert(Point::ORIGIN);
+
+    for line in input.lines() {
+        let (_, instruction) = parse_instruction(line).unwrap();
+        instructions.push(instruction);
+    }
+
+    for instruction in instructions {
+        for _ in 0..instruction.distance {
+            head_tail.move_head(instruction.direction);
+            visited.insert(head_tail.last_tail());
+        }
+    }
+
+    let res = visited.len();
+
+    _print_visited_as_2d(&visited);
+
+    res.into()
+}
+
+fn _print_visited_as_2d(visited: &HashSet<Point>) {
+    let min_x = visited.iter().map(|p| p.x).min().unwrap();
+    let max_x = visited.iter().map(|p| p.x).max().unwrap();
+    let min_y = visited.iter().map(|p| p.y).min().unwrap();
+    let max_y = visited.iter().map(|p| p.y).max().unwrap();
+    for y in min_y..=max_y {
+        for x in min_x..=max_x {
+            if Point::new(x, y) == Point::ORIGIN {
+                print!("s");
+            } else if visited.contains(&Point::new(x, y)) {
+                print!("#");
+            } else {
+                print!(".");
+            }
+        }
+        println!();
+    }
+}
+
+/*
+R 4
+U 4
+L 3
+D 1
+R 4
+D 1
+L 5
+R 2
+*/
+fn parse_instruction(input: &str) -> IResult<&str, Instruction> {
+    let (input, direction) = parse_direction(input)?;
+    let (input, _) = nom::character::complete::space1(input)?;
+    let (input, distance) = nom::character::complete::digit1(input)?;
+    let distance = distance.parse().unwrap();
+    Ok((
+        input,
+        Instruction {
+            direction,
+            distance,
+        },
+    ))
+}
+
+fn parse_direction(input: &str) -> IResult<&str, Direction> {
+    match input.chars().next() {
+        Some(c) => Ok((&input[1..], Direction::from_char(c).expect("Invalid direction"))),
+        None => panic!("Invalid direction"),
+    }
+}
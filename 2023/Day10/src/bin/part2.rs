@@ -0,0 +1 @@
+aoc_common::main!(y2023_day10::part2);
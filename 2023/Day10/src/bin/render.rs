@@ -0,0 +1,48 @@
+// Draws the pipes with box-drawing characters, the loop, inside and outside tiles in colours:
+// `y2023-day10-render [--plain] [--input FILE]`
+// with --plain, the pipes are drawn without colours
+use std::env;
+use std::process::ExitCode;
+
+use aoc_common::{load_input, INPUT_FILE_NAME};
+use y2023_day10::PipeMap;
+
+const USAGE: &str = "usage: y2023-day10-render [--plain] [--input FILE]";
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<(bool, String), String> {
+    let mut plain = false;
+    let mut file_name = INPUT_FILE_NAME.to_string();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--plain" => plain = true,
+            "--input" => file_name = args.next().ok_or(format!("missing value for {arg}"))?,
+            "-h" | "--help" => return Err(USAGE.to_string()),
+            _ => return Err(format!("unknown argument {arg}\n{USAGE}")),
+        }
+    }
+    Ok((plain, file_name))
+}
+
+fn main() -> ExitCode {
+    let (plain, file_name) = match parse_args(env::args().skip(1)) {
+        Ok(args) => args,
+        Err(msg) => {
+            eprintln!("{msg}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let pipes = match load_input!(&file_name) {
+        Ok(input) => PipeMap::parse(&input),
+        Err(e) => {
+            eprintln!("{e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if plain {
+        print!("{pipes}");
+    } else {
+        print!("{}", pipes.render());
+    }
+    ExitCode::SUCCESS
+}
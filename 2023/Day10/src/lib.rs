@@ -0,0 +1,177 @@
+use std::fmt;
+use aoc_common::{Answer, Direction, Grid, Point};
+use aoc_common::Direction::*;
+
+const PIPES: [char; 6] = ['|', '-', 'L', 'J', '7', 'F'];
+
+// ANSI colours of the tiles when rendering
+const LOOP_COLOUR: &str = "\x1b[1;33m";
+const INSIDE_COLOUR: &str = "\x1b[32m";
+const OUTSIDE_COLOUR: &str = "\x1b[2;34m";
+const RESET_COLOUR: &str = "\x1b[0m";
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TileKind {
+    Loop,
+    Inside,
+    Outside,
+}
+
+// The pipes, with S replaced by the pipe it hides
+pub struct PipeMap {
+    tiles: Grid<char>,
+    start: Point,
+}
+
+impl PipeMap {
+    pub fn parse(input: &str) -> Self {
+        let tiles = Grid::parse(input, |c| c);
+        let start = tiles.position(|c| *c == 'S').expect("no starting point");
+        let mut map = Self { tiles, start: start.into() };
+
+        // S connects to the neighbours connecting back to it
+        let connected: Vec<Direction> = Direction::ALL
+            .into_iter()
+            .filter(|d| map.get(map.start.step(*d)).is_some_and(|c| pipe_directions(c).contains(&d.reverse())))
+            .collect();
+        let pipe = PIPES
+            .into_iter()
+            .find(|c| connected.len() == 2 && connected.iter().all(|d| pipe_directions(*c).contains(d)))
+            .expect("S should connect to exactly 2 pipes");
+        map.tiles[start] = pipe;
+        map
+    }
+
+    pub fn start(&self) -> Point {
+        self.start
+    }
+
+    // None out of the map
+    pub fn get(&self, pos: Point) -> Option<char> {
+        pos.to_coord().and_then(|c| self.tiles.get(c)).copied()
+    }
+
+    // returns: the tiles of the loop in order, from S, each one connected to the next and the
+    // last one to S
+    pub fn pipe_loop(&self) -> Vec<Point> {
+        let mut res = vec![self.start];
+        let mut direction = pipe_directions(self.get(self.start).unwrap())[0];
+        let mut pos = self.start.step(direction);
+        while pos != self.start {
+            res.push(pos);
+            // leave the pipe by its other end
+            direction = pipe_directions(self.get(pos).unwrap())
+                .into_iter()
+                .find(|d| *d != direction.reverse())
+                .unwrap();
+            pos = pos.step(direction);
+        }
+        res
+    }
+
+    // Scanning each row from the left, a tile is inside when the loop was crossed an odd
+    // number of times before it. Only the loop tiles going north count as crossings: along a
+    // horizontal stretch, L--7 crosses the loop once and L--J only touches it.
+    pub fn classify(&self) -> Grid<TileKind> {
+        let mut on_loop = self.tiles.map(|_| false);
+        for pos in self.pipe_loop() {
+            on_loop[pos.to_coord().unwrap()] = true;
+        }
+
+        let mut res = Grid::filled(self.tiles.width(), self.tiles.height(), TileKind::Outside);
+        for y in 0..self.tiles.height() {
+            let mut inside = false;
+            for x in 0..self.tiles.width() {
+                if on_loop[(x, y)] {
+                    res[(x, y)] = TileKind::Loop;
+                    if pipe_directions(self.tiles[(x, y)]).contains(&North) {
+                        inside = !inside;
+                    }
+                } else if inside {
+                    res[(x, y)] = TileKind::Inside;
+                }
+            }
+        }
+        res
+    }
+
+    pub fn enclosed_tiles(&self) -> usize {
+        self.classify().cells().iter().filter(|k| **k == TileKind::Inside).count()
+    }
+
+    // the map drawn with box-drawing characters, the loop, inside and outside tiles in
+    // different colours
+    pub fn render(&self) -> String {
+        let kinds = self.classify();
+        let mut res = String::new();
+        for y in 0..self.tiles.height() {
+            // the colour only changes between tiles of different kinds
+            let mut previous = None;
+            for x in 0..self.tiles.width() {
+                let kind = kinds[(x, y)];
+                if previous != Some(kind) {
+                    res.push_str(match kind {
+                        TileKind::Loop => LOOP_COLOUR,
+                        TileKind::Inside => INSIDE_COLOUR,
+                        TileKind::Outside => OUTSIDE_COLOUR,
+                    });
+                    previous = Some(kind);
+                }
+                res.push(box_drawing(self.tiles[(x, y)]));
+            }
+            res.push_str(RESET_COLOUR);
+            res.push('\n');
+        }
+        res
+    }
+}
+
+// the map drawn with box-drawing characters, without colours
+impl fmt::Display for PipeMap {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.tiles.rows() {
+            let line: String = row.iter().map(|c| box_drawing(*c)).collect();
+            writeln!(f, "{line}")?;
+        }
+        Ok(())
+    }
+}
+
+// the directions a pipe connects to, none for the ground
+fn pipe_directions(c: char) -> Vec<Direction> {
+    match c {
+        '7' => vec![South, West],
+        'F' => vec![South, East],
+        'L' => vec![North, East],
+        'J' => vec![North, West],
+        '-' => vec![West, East],
+        '|' => vec![North, South],
+        _ => vec![],
+    }
+}
+
+fn box_drawing(c: char) -> char {
+    match c {
+        '7' => '╗',
+        'F' => '╔',
+        'L' => '╚',
+        'J' => '╝',
+        '-' => '═',
+        '|' => '║',
+        _ => '·',
+    }
+}
+
+pub fn part1(input: &str) -> Answer {
+    let pipes = PipeMap::parse(input);
+
+    // the farthest tile is halfway around the loop
+    let res = pipes.pipe_loop().len() / 2;
+
+    res.into()
+}
+
+pub fn part2(input: &str) -> Answer {
+    let pipes = PipeMap::parse(input);
+    pipes.enclosed_tiles().into()
+}
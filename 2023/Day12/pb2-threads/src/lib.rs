@@ -0,0 +1,193 @@
+use core::fmt;
+use std::collections::HashMap;
+use std::sync::{Mutex, Arc};
+use crate::List::{Cons, Nil};
+use std::thread;
+use aoc_common::Answer;
+
+#[derive(Hash, PartialEq, Eq)]
+enum List<T> {
+    Cons(T, Box<List<T>>),
+    Nil,
+}
+
+impl<T> List<T> {
+    fn from_vec(v: Vec<T>) -> Self {
+        let mut res = Nil;
+        for x in v.into_iter().rev() {
+            res = Cons(x, Box::new(res));
+        }
+        res
+    }
+}
+
+impl<T> List<T>
+    where T: fmt::Display
+{
+    fn fmt_aux(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Cons(a, b) => { write!(f, ", {a}").unwrap(); b.fmt_aux(f) },
+            Nil => Ok(())
+        }
+    }
+}
+
+impl<T> Clone for List<T> 
+    where T: Clone
+{
+    fn clone(&self) -> Self {
+        match self {
+            Cons(a, b) => Cons(a.clone(), b.clone()),
+            Nil => Nil,
+        }
+    }
+}
+
+impl<T> fmt::Display for List<T>
+    where T: fmt::Display
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Cons(a, b) => { 
+                write!(f, "[{a}").unwrap(); b.fmt_aux(f).unwrap(); write!(f, "]")
+            },
+            Nil => write!(f, "[]")
+        }
+    }
+}
+
+#[derive(Clone, Hash, PartialEq, Eq)]
+enum SpringState {
+    OPERATIONAL,
+    DAMAGED,
+    UNKNOWN,
+}
+
+impl fmt::Display for SpringState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::DAMAGED => write!(f, "damaged"),
+            Self::OPERATIONAL => write!(f, "operational"),
+            Self::UNKNOWN => write!(f, "unknown"),
+        }
+    }
+}
+
+/* struct SpringsLine {
+    record: Vec<SpringState>,
+    damaged_size: Vec<usize>,
+} */
+
+fn nb_combinations(record: List<SpringState>, damaged_size: List<usize>, started_group: bool, memo: &mut HashMap<(List<SpringState>,List<usize>), usize>) -> usize {
+    /* println!("#################");
+    println!("record: {}", &record);
+    println!("damaged_size: {}", &damaged_size);
+    println!("started_group: {started_group}"); */
+    {
+        if memo.contains_key(&(record.clone(), damaged_size.clone())) && !started_group {
+            return *memo.get(&(record.clone(), damaged_size.clone())).unwrap();
+        }
+    }
+    let clones = (record.clone(), damaged_size.clone());
+
+    let res = 
+        match (record, damaged_size) {
+            (Nil, Nil) => 1,
+            (Nil, Cons(0, t)) => {
+                match *t {
+                    Nil => 1,
+                    Cons(_, _) => 0,
+                }
+            },
+            (Cons(SpringState::DAMAGED, _), Cons(0, _)) => 0,
+            (Cons(_, b), Cons(0, t)) => {
+                if started_group { nb_combinations(*b, *t, false, memo) }
+                else { 0 }
+            },
+            (Cons(SpringState::DAMAGED, _), Nil) => 0,
+            (Cons(_, b), Nil) => nb_combinations(*b, Nil, false, memo),
+            (Nil, Cons(_, _)) => 0,
+            (Cons(SpringState::DAMAGED, t), Cons(x, y)) => {
+                nb_combinations(*t, Cons(x - 1, y), true, memo)
+            },
+            (Cons(SpringState::UNKNOWN, t), Cons(x, y)) => {
+                if started_group { nb_combinations(*t, Cons(x - 1, y), true, memo) }
+                else {
+                    let t2 = t.clone();
+                    let y2 = y.clone();
+                    nb_combinations(*t, Cons(x - 1, y), true, memo) + nb_combinations(*t2, Cons(x, y2), false, memo)
+                }
+            },
+            (Cons(SpringState::OPERATIONAL, t), damaged_size) => {
+                if started_group { 0 }
+                else { nb_combinations(*t, damaged_size, false, memo) }
+            },
+        };
+    if !started_group {
+        {
+            memo.insert(clones, res);
+        }
+    }
+    res
+}
+
+pub fn part2(input: &str) -> Answer {
+    let total_mutex = Arc::new(Mutex::new(0));
+
+    let mut handles = Vec::new();
+
+    let lines_mutex = Arc::new(Mutex::new(input.lines().map(str::to_string).collect::<Vec<_>>().into_iter().enumerate()));
+    for _ in 0..1 {
+        let lines_mutex = Arc::clone(&lines_mutex);
+        let total_mutex = Arc::clone(&total_mutex);
+        let handle = thread::spawn(move ||{
+            loop {
+                let mut memo: HashMap<(List<SpringState>,List<usize>), usize> = HashMap::new();
+                let (i, line);
+                { 
+                    let (j, l) = match lines_mutex.lock().unwrap().next() {
+                        Some(x) => x,
+                        None => break,
+                    };
+                    (i, line) = (j, l);
+                }
+
+                treat_line(line, &total_mutex, i, &mut memo);
+            }
+        });
+        handles.push(handle);
+    }
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    let total = *total_mutex.lock().unwrap();
+    total.into()
+}
+
+fn treat_line(text: String, total_mutex: &Arc<Mutex<usize>>, i: usize, memo: &mut HashMap<(List<SpringState>,List<usize>), usize>) {
+
+    let (record_text, damaged_size_text) = text.split_once(' ').unwrap();
+    let unfolded_record_text = [record_text, record_text, record_text, record_text, record_text].join("?");
+    let record = unfolded_record_text.chars()
+        .map(
+            |c| match c {
+                '.' => SpringState::OPERATIONAL,
+                '#' => SpringState::DAMAGED,
+                '?' => SpringState::UNKNOWN,
+                _ => unreachable!(),
+            }
+        ).collect::<Vec<_>>();
+    let unfolded_damaged_size_text = 
+        [damaged_size_text, damaged_size_text, damaged_size_text, damaged_size_text, damaged_size_text].join(",");
+    let damaged_size: Vec<usize> = unfolded_damaged_size_text.split(',').map(|c| c.parse().unwrap()).collect();
+
+    //let springs_line = SpringsLine{ record, damaged_size };
+    //thread::sleep(time::Duration::from_millis(30));
+    {
+        let mut total = total_mutex.lock().unwrap();
+        *total += nb_combinations(List::from_vec(record), List::from_vec(damaged_size), false, memo);
+        println!("{}/1000; total = {total} // thread: {:?}", i+1, thread::current().id());
+    }
+}
@@ -1,9 +1,6 @@
 use core::fmt;
-use std::env;
-use std::path::Path;
-use std::fs::File;
-use std::io::{self, BufRead};
 use crate::List::{Cons, Nil};
+use aoc_common::Answer;
 
 enum List<T> {
     Cons(T, Box<List<T>>),
@@ -18,6 +15,13 @@ impl<T> List<T> {
         }
         res
     }
+
+    fn push(self, elt: T) -> Self {
+        match self {
+            Nil => Cons(elt, Box::new(Nil)),
+            Cons(x, y) => Cons(x, Box::new(y.push(elt))),
+        }
+    }
 }
 
 impl<T> List<T>
@@ -55,9 +59,9 @@ impl<T> fmt::Display for List<T>
     }
 }
 
+// operational springs are not kept, they separate the groups of the record
 #[derive(Clone)]
 enum SpringState {
-    OPERATIONAL,
     DAMAGED,
     UNKNOWN,
 }
@@ -66,7 +70,6 @@ impl fmt::Display for SpringState {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             Self::DAMAGED => write!(f, "damaged"),
-            Self::OPERATIONAL => write!(f, "operational"),
             Self::UNKNOWN => write!(f, "unknown"),
         }
     }
@@ -81,6 +84,31 @@ impl fmt::Display for SpringState {
 // Idée: record.split(".") => attribuer un certain nombre de numéros de damaged_size à chaque morceau et calculer les combinaisons plus petites en stockant les résultats
 // Inspiration: explorer la prog dynamique
 
+fn calc_line(record: List<List<SpringState>>, damaged_size: List<usize>) -> usize {
+/*     println!("{} => {}", record, damaged_size);
+    println!("#########"); */
+    match (record, damaged_size) {
+        (Nil, Nil) => 1,
+        (Nil, Cons(_, _)) => 0, 
+        (Cons(t, q), Nil) => nb_combinations(t, Nil, false) * calc_line(*q, Nil),
+        (Cons(t, q), list) => consume_c(t, *q, Nil, list),
+    }
+}
+
+fn consume_c(t: List<SpringState>, q: List<List<SpringState>>, e: List<usize>, list: List<usize>) -> usize {
+    /* println!("{} => {}", a, assigned_to_a);
+    println!("{} => {}", b, d);
+    println!("#########"); */
+    match list {
+        Nil => nb_combinations(t, e, false) * calc_line(q, Nil),
+        Cons(a, b) => {
+            println!("{} // {} // {}", Cons(t.clone(), Box::new(q.clone())), e.clone().push(a), b.clone());
+            consume_c(t.clone(), q.clone(), e.clone().push(a), *b.clone())
+            + nb_combinations(t.clone(), e.clone(), false) * calc_line(q.clone(), Cons(a, b.clone()))
+        },
+    }
+}
+
 fn nb_combinations(record: List<SpringState>, damaged_size: List<usize>, started_group: bool) -> usize {
     /* println!("#################");
     println!("record: {}", &record);
@@ -113,54 +141,40 @@ fn nb_combinations(record: List<SpringState>, damaged_size: List<usize>, started
                 nb_combinations(*t, Cons(x - 1, y), true) + nb_combinations(*t2, Cons(x, y2), false)
             }
         },
-        (Cons(SpringState::OPERATIONAL, t), damaged_size) => {
-            if started_group { 0 }
-            else { nb_combinations(*t, damaged_size, false) }
-        },
     }
 }
 
-fn main() {
-    let file = env::current_dir().unwrap()
-        .parent().unwrap()
-        .join(
-            Path::new("input_first_lines.txt")
-        );
-
+pub fn part2(input: &str) -> Answer {
     let mut total = 0;
 
-    if let Ok(lines) = read_lines(file) {
-        for (i, line) in lines.enumerate() {
-            if let Ok(text) = line {
-
-                let (record_text, damaged_size_text) = text.split_once(' ').unwrap();
-                let unfolded_record_text = vec![record_text.clone(), record_text.clone(), record_text.clone(), record_text.clone(), record_text.clone()].join("?");
-                let record = unfolded_record_text.chars()
-                    .map(
-                        |c| match c {
-                            '.' => SpringState::OPERATIONAL,
-                            '#' => SpringState::DAMAGED,
-                            '?' => SpringState::UNKNOWN,
-                            _ => unreachable!(),
-                        }
-                    ).collect::<Vec<_>>();
-                let unfolded_damaged_size_text = 
-                    vec![damaged_size_text.clone(), damaged_size_text.clone(), damaged_size_text.clone(), damaged_size_text.clone(), damaged_size_text.clone()].join(",");
-                let damaged_size: Vec<usize> = unfolded_damaged_size_text.split(',').map(|c| c.parse().unwrap()).collect();
-
-                //let springs_line = SpringsLine{ record, damaged_size };
-                total += nb_combinations(List::from_vec(record), List::from_vec(damaged_size), false);
-                println!("{}/1000; total = {total}", i+1);
+    for (i, text) in input.lines().enumerate() {
+
+        let (record_text, damaged_size_text) = text.split_once(' ').unwrap();
+        let unfolded_record_text = [record_text; 5].join("?");
+        let record = unfolded_record_text.split(".")
+            .map(|s| List::from_vec(
+                s.chars()
+                .map(
+                    |c| match c {
+                        '#' => SpringState::DAMAGED,
+                        '?' => SpringState::UNKNOWN,
+                        _ => unreachable!(),
+                    }
+                ).collect::<Vec<_>>()
+            ))
+            .filter(|liste| !matches!(liste, Nil))
+            .collect();
+        let unfolded_damaged_size_text = 
+            [damaged_size_text; 5].join(",");
+        let damaged_size: Vec<usize> = unfolded_damaged_size_text.split(',').map(|c| c.parse().unwrap()).collect();
+
+        println!("{}/1000", i+1);
+
+        //let springs_line = SpringsLine{ record, damaged_size };
+        //total += nb_combinations(List::from_vec(record), List::from_vec(damaged_size), false);
+        total += calc_line(List::from_vec(record), List::from_vec(damaged_size))
 
-            }
-        }
     }
 
-    println!("{total}");
-}
-
-fn read_lines<P>(filename: P) -> io::Result<io::Lines<io::BufReader<File>>>
-where P: AsRef<Path>, {
-    let file = File::open(filename)?;
-    Ok(io::BufReader::new(file).lines())
+    total.into()
 }
@@ -0,0 +1,106 @@
+use std::thread;
+use aoc_common::Answer;
+
+const UNFOLD_FACTOR: usize = 5;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SpringState {
+    OPERATIONAL,
+    DAMAGED,
+    UNKNOWN,
+}
+
+impl SpringState {
+    fn from_char(c: char) -> Self {
+        match c {
+            '.' => SpringState::OPERATIONAL,
+            '#' => SpringState::DAMAGED,
+            '?' => SpringState::UNKNOWN,
+            _ => unreachable!(),
+        }
+    }
+}
+
+// ???.### 1,1,3
+pub fn parse_line(text: &str) -> (Vec<SpringState>, Vec<usize>) {
+    let (record_text, groups_text) = text.split_once(' ').unwrap();
+    let record = record_text.chars().map(SpringState::from_char).collect();
+    let groups = groups_text.split(',').map(|c| c.parse().unwrap()).collect();
+    (record, groups)
+}
+
+// returns: the number of ways to replace the unknown springs so that the damaged ones form
+// groups, once record is repeated unfold_factor times separated by unknown springs, and
+// groups unfold_factor times
+pub fn count_arrangements(record: &[SpringState], groups: &[usize], unfold_factor: usize) -> usize {
+    let mut unfolded_record = Vec::with_capacity((record.len() + 1) * unfold_factor);
+    for i in 0..unfold_factor {
+        if i > 0 {
+            unfolded_record.push(SpringState::UNKNOWN);
+        }
+        unfolded_record.extend_from_slice(record);
+    }
+    let unfolded_groups = groups.repeat(unfold_factor);
+    count_folded(&unfolded_record, &unfolded_groups)
+}
+
+// ways[i][j]: number of arrangements of record[i..] with groups[j..], from the end of the record
+fn count_folded(record: &[SpringState], groups: &[usize]) -> usize {
+    let (n, m) = (record.len(), groups.len());
+
+    // operational_before[i]: number of operational springs in record[..i], a group fits
+    // from i to i + size if there is none in between
+    let mut operational_before = vec![0; n + 1];
+    for (i, state) in record.iter().enumerate() {
+        operational_before[i + 1] = operational_before[i] + usize::from(*state == SpringState::OPERATIONAL);
+    }
+
+    let mut ways = vec![vec![0; m + 1]; n + 1];
+    ways[n][m] = 1;
+    for i in (0..n).rev() {
+        for j in 0..=m {
+            let mut res = 0;
+            // record[i] operational
+            if record[i] != SpringState::DAMAGED {
+                res += ways[i + 1][j];
+            }
+            // group j starts at i, followed by an operational spring or the end
+            if record[i] != SpringState::OPERATIONAL && j < m {
+                let end = i + groups[j];
+                if end <= n
+                    && operational_before[end] == operational_before[i]
+                    && record.get(end) != Some(&SpringState::DAMAGED)
+                {
+                    res += ways[(end + 1).min(n)][j + 1];
+                }
+            }
+            ways[i][j] = res;
+        }
+    }
+    ways[0][0]
+}
+
+// the lines are split between the available threads, each counts the arrangements of its share
+fn total_arrangements(input: &str, unfold_factor: usize) -> usize {
+    let lines: Vec<(Vec<SpringState>, Vec<usize>)> = input.lines().map(parse_line).collect();
+    let nb_threads = thread::available_parallelism().map_or(1, |n| n.get());
+    let chunk_size = lines.len().div_ceil(nb_threads).max(1);
+
+    thread::scope(|s| {
+        let handles: Vec<_> = lines
+            .chunks(chunk_size)
+            .map(|chunk| s.spawn(move || {
+                chunk.iter().map(|(record, groups)| count_arrangements(record, groups, unfold_factor)).sum::<usize>()
+            }))
+            .collect();
+        handles.into_iter().map(|h| h.join().unwrap()).sum()
+    })
+}
+
+pub fn part1(input: &str) -> Answer {
+    total_arrangements(input, 1).into()
+}
+
+pub fn part2(input: &str) -> Answer {
+    total_arrangements(input, UNFOLD_FACTOR).into()
+}
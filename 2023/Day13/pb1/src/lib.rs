@@ -0,0 +1,46 @@
+use aoc_common::{Answer, Grid};
+
+// returns the number of lines left to the symmetry axis
+fn find_vertical_symmetry(map: &Grid<char>) -> Option<usize> {
+    // a vertical axis in the map is a horizontal one in its transposition
+    find_horizontal_symmetry(&map.transpose())
+}
+
+// returns the number of lines above the symmetry axis
+fn find_horizontal_symmetry(map: &Grid<char>) -> Option<usize> {
+    for i1 in 0..map.height()-1 {
+        let i2 = i1 + 1;
+        // is i1/i2 a symmetry axis ?
+        if test_symmetry_horizontal(map, i1, i2) {
+            return Some(i2);
+        }
+    }
+    None
+}
+
+fn test_symmetry_horizontal(map: &Grid<char>, i1: usize, i2: usize) -> bool {
+    if i1 == 0 || i2 == map.height()-1 {
+        return map.row(i1) == map.row(i2);
+    }
+    map.row(i1) == map.row(i2) && test_symmetry_horizontal(map, i1-1, i2+1)
+}
+
+pub fn part1(input: &str) -> Answer {
+    let mut vertical_res = 0;
+    let mut horizontal_res = 0;
+
+    for block in input.split("\n\n").filter(|block| !block.trim().is_empty()) {
+        let map = Grid::parse(block, |c| c);
+        match find_vertical_symmetry(&map) {
+            Some(a) => vertical_res += a,
+            None => match find_horizontal_symmetry(&map) {
+                Some(b) => horizontal_res += b,
+                None => unreachable!(),
+            }
+        }
+    }
+
+    let total = vertical_res + 100 * horizontal_res;
+
+    total.into()
+}
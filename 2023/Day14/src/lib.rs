@@ -0,0 +1,118 @@
+use std::fmt;
+use aoc_common::cycle::fast_forward;
+use aoc_common::{Answer, Direction};
+
+const CYCLE_NUMBER: usize = 1000000000;
+// the directions of a spin cycle
+const DIRECTIONS: [Direction; 4] = [Direction::North, Direction::West, Direction::South, Direction::East];
+
+// One bitset per row, bit j for column j
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Platform {
+    width: usize,
+    round: Vec<u128>,
+    cube: Vec<u128>,
+}
+
+impl Platform {
+    pub fn parse(input: &str) -> Self {
+        let width = input.lines().next().map_or(0, |l| l.len());
+        assert!(width <= u128::BITS as usize, "the platform is too wide for the bitsets");
+        let row = |line: &str, rock: char| {
+            line.chars().enumerate().filter(|(_, c)| *c == rock).fold(0, |row, (j, _)| row | 1 << j)
+        };
+        Self {
+            width,
+            round: input.lines().map(|l| row(l, 'O')).collect(),
+            cube: input.lines().map(|l| row(l, '#')).collect(),
+        }
+    }
+
+    fn full_row(&self) -> u128 {
+        if self.width == u128::BITS as usize { u128::MAX } else { (1 << self.width) - 1 }
+    }
+
+    // every round rock rolls in direction, until it stops against a rock or the edge:
+    // all the rocks that can move do it by one place together, until none can
+    pub fn tilt(&mut self, direction: Direction) {
+        match direction {
+            Direction::North | Direction::South => {
+                let rows: Vec<(usize, usize)> = if direction == Direction::North {
+                    (1..self.round.len()).map(|i| (i, i - 1)).collect()
+                } else {
+                    (1..self.round.len()).rev().map(|i| (i - 1, i)).collect()
+                };
+                let mut moved = true;
+                while moved {
+                    moved = false;
+                    for &(from, to) in rows.iter() {
+                        let moving = self.round[from] & !(self.round[to] | self.cube[to]);
+                        if moving != 0 {
+                            self.round[from] &= !moving;
+                            self.round[to] |= moving;
+                            moved = true;
+                        }
+                    }
+                }
+            }
+            Direction::West | Direction::East => {
+                let full_row = self.full_row();
+                for (round, cube) in self.round.iter_mut().zip(self.cube.iter()) {
+                    loop {
+                        let free = !(*round | cube) & full_row;
+                        let moving = if direction == Direction::West { *round & (free << 1) } else { *round & (free >> 1) };
+                        if moving == 0 {
+                            break;
+                        }
+                        let moved = if direction == Direction::West { moving >> 1 } else { moving << 1 };
+                        *round = (*round & !moving) | moved;
+                    }
+                }
+            }
+        }
+    }
+
+    pub fn spin_cycle(&mut self) {
+        for direction in DIRECTIONS {
+            self.tilt(direction);
+        }
+    }
+
+    // load on the north support beams
+    pub fn load(&self) -> usize {
+        self.round.iter().enumerate().map(|(i, row)| (self.round.len() - i) * row.count_ones() as usize).sum()
+    }
+}
+
+impl fmt::Display for Platform {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (round, cube) in self.round.iter().zip(self.cube.iter()) {
+            for j in 0..self.width {
+                let c = if round >> j & 1 == 1 { 'O' } else if cube >> j & 1 == 1 { '#' } else { '.' };
+                write!(f, "{c}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+pub fn part1(input: &str) -> Answer {
+    let mut platform = Platform::parse(input);
+    platform.tilt(Direction::North);
+    platform.load().into()
+}
+
+pub fn part2(input: &str) -> Answer {
+    let platform = Platform::parse(input);
+
+    // the spin cycles soon bring back a platform seen before, then loop
+    let spin = |p: &Platform| {
+        let mut p = p.clone();
+        p.spin_cycle();
+        p
+    };
+    let (platform, _) = fast_forward(platform, spin, CYCLE_NUMBER);
+
+    platform.load().into()
+}
@@ -0,0 +1,28 @@
+use aoc_common::Answer;
+
+pub fn part1(input: &str) -> Answer {
+    let mut total = 0;
+
+    for text in input.lines() {
+
+        if text.contains('\n') { unreachable!() }
+        let split = text.split(",");
+
+        for s in split {
+            total += hash_algorithm(s);
+        }
+
+    }
+
+    total.into()
+}
+
+fn hash_algorithm(s: &str) -> usize {
+    let mut current_value = 0;
+    for c in s.chars() {
+        current_value += c as usize; // ascii representation of c
+        current_value *= 17;
+        current_value %= 256;
+    }
+    current_value
+}
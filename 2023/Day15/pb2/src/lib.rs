@@ -0,0 +1,70 @@
+use std::collections::HashMap;
+use aoc_common::Answer;
+
+pub fn part2(input: &str) -> Answer {
+
+    let mut boxes: HashMap<usize, Vec<(String, usize)>> = HashMap::new();
+
+    for text in input.lines() {
+
+        if text.contains('\n') { unreachable!() }
+        let split = text.split(",");
+
+        for s in split { // only one line
+
+            let op: char;
+            let label: &str;
+            let value: usize;
+            if s.contains('-') {
+                op = '-';
+                label = &s[..(s.len()-1)];
+                value = 0;
+            } else if s.contains('=') {
+                op = '=';
+                let (lab, val) = s.split_once("=").unwrap();
+                label = lab;
+                value = val.parse().unwrap();
+            } else {
+                unreachable!();
+            }
+            let box_index = hash_algorithm(label);
+            let the_box = boxes.entry(box_index).or_default();
+            match op {
+                '-' => if let Some(i) = the_box.iter().position(|(lens, _)| lens == label) {
+                        the_box.remove(i);
+                    },
+                '=' => match the_box.iter().position(|(lens, _)| lens == label) {
+                        None => the_box.push((label.to_string(), value)),
+                        Some(i) => the_box.get_mut(i).unwrap().1 = value,
+                    },
+                _ => unreachable!(),
+            }
+        }
+
+    }
+
+    let mut total = 0;
+
+    for i in 0..256 {
+        if let Some(the_box) = boxes.get(&i) {
+            let mut box_total = 0;
+            for (j, (_, val)) in the_box.iter().enumerate() {
+                let lens_score = (i+1) * (j+1) * *val;
+                box_total += lens_score;
+            }
+            total += box_total;
+        }
+    }
+
+    total.into()
+}
+
+fn hash_algorithm(s: &str) -> usize {
+    let mut current_value = 0;
+    for c in s.chars() {
+        current_value += c as usize; // ascii representation of c
+        current_value *= 17;
+        current_value %= 256;
+    }
+    current_value
+}
@@ -0,0 +1,117 @@
+use std::collections::HashSet;
+use aoc_common::{Answer, Direction, Grid, Point};
+
+#[derive(Clone, Copy)]
+struct Beam {
+    position: Point,
+    direction: Direction,
+    rows: usize,
+    cols: usize,
+}
+
+impl PartialEq for Beam {
+    fn eq(&self, other: &Self) -> bool {
+        self.position == other.position && self.direction == other.direction
+    }
+}
+
+impl Beam {
+
+    fn new_beams(&self, tile: char) -> Vec<Option<Self>> {
+        match tile {
+            '.' => vec![self.take_direction(self.direction)],
+            '-' => match self.direction {
+                Direction::West | Direction::East => vec![self.take_direction(self.direction)],
+                Direction::North | Direction::South => vec![self.take_direction(Direction::West), self.take_direction(Direction::East)],
+            },
+            '|' => match self.direction {
+                Direction::North | Direction::South => vec![self.take_direction(self.direction)],
+                Direction::West | Direction::East => vec![self.take_direction(Direction::North), self.take_direction(Direction::South)],
+            },
+            '/' => match self.direction {
+                Direction::North => vec![self.take_direction(Direction::East)],
+                Direction::West => vec![self.take_direction(Direction::South)],
+                Direction::South => vec![self.take_direction(Direction::West)],
+                Direction::East => vec![self.take_direction(Direction::North)],
+            },
+            '\\' => match self.direction {
+                Direction::North => vec![self.take_direction(Direction::West)],
+                Direction::West => vec![self.take_direction(Direction::North)],
+                Direction::South => vec![self.take_direction(Direction::East)],
+                Direction::East => vec![self.take_direction(Direction::South)],
+            },
+            _ => unreachable!(),
+        }
+    }
+
+    fn take_direction(&self, dir: Direction) -> Option<Self> {
+        let next = self.position.step(dir);
+
+        if out_of_bounds(self.rows, self.cols, next) {
+            None
+        } else {
+            Some(Self {
+                position: next,
+                direction: dir,
+                rows: self.rows,
+                cols: self.cols,
+            })
+        }
+    }
+
+}
+
+fn out_of_bounds(rows: usize, cols: usize, p: Point) -> bool {
+    !(p.x >= 0 && p.x < cols as isize && p.y >= 0 && p.y < rows as isize)
+}
+
+pub fn part1(input: &str) -> Answer {
+    let mut beams: Vec<Beam> = Vec::new();
+    let mut current_beams: Vec<Beam> = Vec::new();
+
+    let map = Grid::parse(input, |c| c);
+    let rows = map.height();
+    let cols = map.width();
+
+    current_beams.push(Beam { position: Point::new(0, 0), direction: Direction::East, rows, cols });
+    let mut energized_map: HashSet<Point> = HashSet::new();
+
+    loop {
+        if current_beams.is_empty() {
+            break
+        }
+
+        let current_beam = current_beams.pop().unwrap();
+
+        energized_map.insert(current_beam.position);
+        //energized_print(&energized_map, rows, cols);
+        
+        beams.push(current_beam);
+        let mut next_beams= 
+            current_beam.new_beams(map[current_beam.position.to_coord().unwrap()])
+            .into_iter()
+            .flatten()
+            .filter(|b| !(current_beams.contains(b) || beams.contains(b)))
+            .collect();
+        current_beams.append(&mut next_beams);
+    }
+
+    energized_print(&energized_map, rows, cols);
+
+    let total = energized_map.len();
+    total.into()
+}
+
+fn energized_print(energized: &HashSet<Point>, rows: usize, cols: usize) {
+    for i in 0..rows {
+        for j in 0..cols {
+            if energized.contains(&Point::new(j as isize, i as isize)) {
+                print!("#");
+            } else {
+                print!(".");
+            }
+        }
+        println!();
+    }
+    println!();
+}
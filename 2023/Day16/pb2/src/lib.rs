@@ -0,0 +1,140 @@
+use std::collections::HashSet;
+use std::time::SystemTime;
+use aoc_common::{Answer, Direction, Grid, Point};
+
+#[derive(Clone, Copy)]
+struct Beam {
+    position: Point,
+    direction: Direction,
+    rows: usize,
+    cols: usize,
+}
+
+impl PartialEq for Beam {
+    fn eq(&self, other: &Self) -> bool {
+        self.position == other.position && self.direction == other.direction
+    }
+}
+
+impl Beam {
+
+    fn new_beams(&self, tile: char) -> Vec<Option<Self>> {
+        match tile {
+            '.' => vec![self.take_direction(self.direction)],
+            '-' => match self.direction {
+                Direction::West | Direction::East => vec![self.take_direction(self.direction)],
+                Direction::North | Direction::South => vec![self.take_direction(Direction::West), self.take_direction(Direction::East)],
+            },
+            '|' => match self.direction {
+                Direction::North | Direction::South => vec![self.take_direction(self.direction)],
+                Direction::West | Direction::East => vec![self.take_direction(Direction::North), self.take_direction(Direction::South)],
+            },
+            '/' => match self.direction {
+                Direction::North => vec![self.take_direction(Direction::East)],
+                Direction::West => vec![self.take_direction(Direction::South)],
+                Direction::South => vec![self.take_direction(Direction::West)],
+                Direction::East => vec![self.take_direction(Direction::North)],
+            },
+            '\\' => match self.direction {
+                Direction::North => vec![self.take_direction(Direction::West)],
+                Direction::West => vec![self.take_direction(Direction::North)],
+                Direction::South => vec![self.take_direction(Direction::East)],
+                Direction::East => vec![self.take_direction(Direction::South)],
+            },
+            _ => unreachable!(),
+        }
+    }
+
+    fn take_direction(&self, dir: Direction) -> Option<Self> {
+        let next = self.position.step(dir);
+
+        if out_of_bounds(self.rows, self.cols, next) {
+            None
+        } else {
+            Some(Self {
+                position: next,
+                direction: dir,
+                rows: self.rows,
+                cols: self.cols,
+            })
+        }
+    }
+
+}
+
+fn out_of_bounds(rows: usize, cols: usize, p: Point) -> bool {
+    !(p.x >= 0 && p.x < cols as isize && p.y >= 0 && p.y < rows as isize)
+}
+
+pub fn part2(input: &str) -> Answer {
+    let map = Grid::parse(input, |c| c);
+    let rows = map.height();
+    let cols = map.width();
+
+    let mut res = 0;
+
+    let mut init_beams = Vec::new();
+
+    // north wall
+    for j in 0..cols {
+        let init_beam = Beam { position: Point::new(j as isize, 0), direction: Direction::South, rows, cols };
+        init_beams.push(init_beam);
+    }
+    // west wall
+    for i in 0..rows {
+        let init_beam = Beam { position: Point::new(0, i as isize), direction: Direction::East, rows, cols };
+        init_beams.push(init_beam);
+    }
+
+    // south wall
+    for j in 0..cols {
+        let init_beam = Beam { position: Point::new(j as isize, (rows-1) as isize), direction: Direction::North, rows, cols };
+        init_beams.push(init_beam);
+    }
+    // east wall
+    for i in 0..rows {
+        let init_beam = Beam { position: Point::new((cols-1) as isize, i as isize), direction: Direction::West, rows, cols };
+        init_beams.push(init_beam);
+    }
+
+    let start = SystemTime::now();
+    let size = init_beams.len();
+    for (num, init_beam) in init_beams.iter().enumerate() {
+        res = res.max(calc_energized(*init_beam, &map));
+        println!("{}/{}, time: {}s", num, size, SystemTime::now().duration_since(start).unwrap().as_secs());
+    }
+    
+    res.into()
+}
+
+fn calc_energized(init_beam: Beam, map: &Grid<char>) -> usize {
+
+    let mut beams: Vec<Beam> = Vec::new();
+    let mut current_beams: Vec<Beam> = Vec::new();
+
+    current_beams.push(init_beam);
+
+    
+    let mut energized_map: HashSet<Point> = HashSet::new();
+
+    loop {
+        if current_beams.is_empty() {
+            break
+        }
+
+        let current_beam = current_beams.pop().unwrap();
+
+        energized_map.insert(current_beam.position);
+        
+        beams.push(current_beam);
+        let mut next_beams= 
+            current_beam.new_beams(map[current_beam.position.to_coord().unwrap()])
+            .into_iter()
+            .flatten()
+            .filter(|b| !(current_beams.contains(b) || beams.contains(b)))
+            .collect();
+        current_beams.append(&mut next_beams);
+    }
+
+    energized_map.len()
+}
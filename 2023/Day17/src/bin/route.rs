@@ -0,0 +1,67 @@
+// Draws the best route of a crucible on the map, with movement rules given on the command line
+use std::env;
+use std::process::ExitCode;
+
+use aoc_common::{load_input, INPUT_FILE_NAME};
+use y2023_day17::{parse, render, Crucible, CRUCIBLE, ULTRA_CRUCIBLE};
+
+const USAGE: &str = "usage: y2023-day17-route [--ultra] [--min N] [--max N] [--turns l|r|lr|lru...] [--input FILE]";
+
+// the crucible of part 1 unless `--ultra`, then each option overrides a rule
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<(Crucible, String), String> {
+    let mut crucible = CRUCIBLE;
+    let mut file_name = INPUT_FILE_NAME.to_string();
+    while let Some(arg) = args.next() {
+        if arg == "--ultra" {
+            crucible.min_straight = ULTRA_CRUCIBLE.min_straight;
+            crucible.max_straight = ULTRA_CRUCIBLE.max_straight;
+            continue;
+        }
+        let value = match arg.as_str() {
+            "--min" | "--max" | "--turns" | "--input" => args.next().ok_or(format!("missing value for {arg}"))?,
+            "-h" | "--help" => return Err(USAGE.to_string()),
+            _ => return Err(format!("unknown argument {arg}\n{USAGE}")),
+        };
+        let invalid = |_| format!("invalid value for {arg}: {value}");
+        match arg.as_str() {
+            "--min" => crucible.min_straight = value.parse().map_err(invalid)?,
+            "--max" => crucible.max_straight = value.parse().map_err(invalid)?,
+            "--turns" => {
+                if !value.chars().all(|c| "lru".contains(c)) {
+                    return Err(format!("invalid value for {arg}: {value}"));
+                }
+                crucible.turn_left = value.contains('l');
+                crucible.turn_right = value.contains('r');
+                crucible.u_turn = value.contains('u');
+            }
+            _ => file_name = value,
+        }
+    }
+    Ok((crucible, file_name))
+}
+
+fn main() -> ExitCode {
+    let (crucible, file_name) = match parse_args(env::args().skip(1)) {
+        Ok(args) => args,
+        Err(msg) => {
+            eprintln!("{msg}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let map = match load_input!(&file_name) {
+        Ok(input) => parse(&input),
+        Err(e) => {
+            eprintln!("{e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match crucible.best_route(&map) {
+        Some(route) => {
+            println!("{}", render(&map, &route));
+            println!("Heat loss: {}", route.cost);
+        }
+        None => println!("No route to the bottom right block with {crucible:?}"),
+    }
+    ExitCode::SUCCESS
+}
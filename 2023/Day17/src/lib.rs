@@ -0,0 +1,96 @@
+use aoc_common::search::{dijkstra, Path};
+use aoc_common::{Answer, Direction, Grid, Point};
+
+// How a crucible is allowed to move: after each turn it goes straight for
+// `min_straight` to `max_straight` blocks, then turns again.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Crucible {
+    pub min_straight: usize,
+    pub max_straight: usize,
+    pub turn_left: bool,
+    pub turn_right: bool,
+    pub u_turn: bool,
+}
+
+pub const CRUCIBLE: Crucible = Crucible { min_straight: 1, max_straight: 3, turn_left: true, turn_right: true, u_turn: false };
+pub const ULTRA_CRUCIBLE: Crucible = Crucible { min_straight: 4, max_straight: 10, ..CRUCIBLE };
+
+impl Crucible {
+    // the directions the crucible can take after going in `direction`
+    fn turns(&self, direction: Direction) -> Vec<Direction> {
+        let mut res = Vec::new();
+        if self.turn_left {
+            res.push(direction.turn_left());
+        }
+        if self.turn_right {
+            res.push(direction.turn_right());
+        }
+        if self.u_turn {
+            res.push(direction.reverse());
+        }
+        res
+    }
+
+    // returns: list of all neighbours of state and their distance from it
+    fn neighbours(&self, state: &State, map: &Grid<usize>) -> Vec<(State, usize)> {
+        let mut neigh = Vec::new();
+        for direction in self.turns(state.direction) {
+            let mut position = state.position;
+            let mut distance_from_state = 0;
+            for k in 1..=self.max_straight {
+                position = position.step(direction);
+                let Some(&heat_loss) = position.to_coord().and_then(|c| map.get(c)) else { break };
+                distance_from_state += heat_loss;
+                if k >= self.min_straight {
+                    neigh.push((State { position, direction }, distance_from_state));
+                }
+            }
+        }
+        neigh
+    }
+
+    // returns: the route with the least heat loss from the top left to the bottom right block,
+    // the path cost is the heat loss and its states are the blocks where the crucible turns
+    // None if the rules do not allow to reach the bottom right block
+    pub fn best_route(&self, map: &Grid<usize>) -> Option<Path<State>> {
+        let end = Point::new(map.width() as isize - 1, map.height() as isize - 1);
+
+        // the crucible has not moved yet: starting from every direction, whatever the allowed
+        // turns, the first move can go any way
+        let starts = Direction::ALL.map(|direction| State { position: Point::ORIGIN, direction });
+        dijkstra(starts, |state| self.neighbours(state, map), |state| state.position == end)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct State {
+    pub position: Point,
+    pub direction: Direction, // direction from which the position is reached
+}
+
+pub fn parse(input: &str) -> Grid<usize> {
+    Grid::parse(input, |c| c.to_digit(10).unwrap() as usize)
+}
+
+// the map with an arrow on every block the crucible goes through
+pub fn render(map: &Grid<usize>, route: &Path<State>) -> String {
+    let mut drawing = map.map(|heat_loss| char::from_digit(*heat_loss as u32, 10).unwrap());
+    for (from, to) in route.states.iter().zip(route.states.iter().skip(1)) {
+        let mut position = from.position;
+        while position != to.position {
+            position = position.step(to.direction);
+            drawing[position.to_coord().unwrap()] = to.direction.to_arrow();
+        }
+    }
+    drawing.to_string()
+}
+
+pub fn part1(input: &str) -> Answer {
+    let map = parse(input);
+    CRUCIBLE.best_route(&map).unwrap().cost.into()
+}
+
+pub fn part2(input: &str) -> Answer {
+    let map = parse(input);
+    ULTRA_CRUCIBLE.best_route(&map).unwrap().cost.into()
+}
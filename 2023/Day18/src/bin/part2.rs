@@ -0,0 +1 @@
+aoc_common::main!(y2023_day18::part2);
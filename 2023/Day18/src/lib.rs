@@ -0,0 +1,39 @@
+use aoc_common::polygon::Polygon;
+use aoc_common::{Answer, Direction, Point};
+
+// R 6 (#70c710)
+fn parse_step(line: &str) -> (Direction, usize) {
+    let mut split = line.split(' ');
+    let direction = Direction::from_char(split.next().unwrap().chars().next().unwrap()).unwrap();
+    let length = split.next().unwrap().parse().unwrap();
+    (direction, length)
+}
+
+// the real step is hidden in the colour: 5 hexadecimal digits for the length, then the
+// direction, 0 to 3 for R, D, L and U
+fn parse_colour_step(line: &str) -> (Direction, usize) {
+    let (_, colour) = line.split_once('#').unwrap();
+    let length = usize::from_str_radix(&colour[0..5], 16).unwrap();
+    let direction = match &colour[5..6] {
+        "0" => Direction::East,
+        "1" => Direction::South,
+        "2" => Direction::West,
+        "3" => Direction::North,
+        _ => unreachable!(),
+    };
+    (direction, length)
+}
+
+// the trench goes through the centers of the dug cubes: the lagoon holds the lattice points
+// inside the polygon and on its edges
+fn lagoon_volume(steps: impl Iterator<Item = (Direction, usize)>) -> usize {
+    Polygon::from_steps(Point::ORIGIN, steps).lattice_points()
+}
+
+pub fn part1(input: &str) -> Answer {
+    lagoon_volume(input.lines().map(parse_step)).into()
+}
+
+pub fn part2(input: &str) -> Answer {
+    lagoon_volume(input.lines().map(parse_colour_step)).into()
+}
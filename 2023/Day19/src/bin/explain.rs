@@ -0,0 +1,41 @@
+// Prints every region of accepted parts with the chain of workflows leading to it:
+// `y2023-day19-explain [--input FILE]`
+use std::env;
+use std::process::ExitCode;
+
+use aoc_common::{load_input, Solution, INPUT_FILE_NAME};
+use y2023_day19::{explain, Day19};
+
+const USAGE: &str = "usage: y2023-day19-explain [--input FILE]";
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<String, String> {
+    let mut file_name = INPUT_FILE_NAME.to_string();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" => file_name = args.next().ok_or(format!("missing value for {arg}"))?,
+            "-h" | "--help" => return Err(USAGE.to_string()),
+            _ => return Err(format!("unknown argument {arg}\n{USAGE}")),
+        }
+    }
+    Ok(file_name)
+}
+
+fn main() -> ExitCode {
+    let file_name = match parse_args(env::args().skip(1)) {
+        Ok(file_name) => file_name,
+        Err(msg) => {
+            eprintln!("{msg}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let (decision, _) = match load_input!(&file_name) {
+        Ok(input) => Day19::parse(&input),
+        Err(e) => {
+            eprintln!("{e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    print!("{}", explain(&decision));
+    ExitCode::SUCCESS
+}
@@ -0,0 +1 @@
+aoc_common::main!(aoc_common::solution::part2::<y2023_day19::Day19>);
@@ -0,0 +1,324 @@
+use crate::ResultWorkflow::*;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fmt;
+use aoc_common::interval;
+use aoc_common::{Answer, Solution};
+
+const SIZE_OF_PART: usize = 4000;
+const FIRST_WORKFLOW: &str = "in";
+
+pub struct Workflow {
+    filter: Vec<(Filter, ResultWorkflow)>,
+    default: ResultWorkflow,
+}
+
+impl Workflow {
+    fn new(default: ResultWorkflow) -> Self {
+        Self {
+            filter: Vec::new(),
+            default,
+        }
+    }
+
+    fn add_filter(&mut self, filter: Filter, res: ResultWorkflow) {
+        self.filter.push((filter, res));
+    }
+}
+
+// category `ord` val, e.g. a<2006
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Filter {
+    pub category: char,
+    pub ord: Ordering,
+    pub val: usize,
+}
+
+impl Filter {
+    fn new(category: char, ord: Ordering, val: usize) -> Self {
+        Self {
+            category,
+            ord,
+            val,
+        }
+    }
+
+    fn matches(&self, part: &Part) -> bool {
+        part.get(self.category).cmp(&self.val) == self.ord
+    }
+
+    // returns: (the parts of range that match, the ones that do not)
+    fn split(&self, part: &PartRange) -> (Option<PartRange>, Option<PartRange>) {
+        let axis = axis(self.category);
+        match self.ord {
+            Ordering::Less => part.split(axis, self.val as i64),
+            Ordering::Greater => {
+                let (below, above) = part.split(axis, self.val as i64 + 1);
+                (above, below)
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl fmt::Display for Filter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let ord = match self.ord {
+            Ordering::Less => '<',
+            Ordering::Greater => '>',
+            Ordering::Equal => '=',
+        };
+        write!(f, "{}{}{}", self.category, ord, self.val)
+    }
+}
+
+#[derive(Clone, Eq, PartialEq)]
+enum ResultWorkflow {
+    ACCEPTED,
+    REJECTED,
+    SEND(String), // workflow name
+}
+
+impl ResultWorkflow {
+    fn from_str(s: &str) -> Self {
+        match s {
+            "A" => ACCEPTED,
+            "R" => REJECTED,
+            x => SEND(x.to_string()),
+        }
+    }
+}
+
+// The workflows starting from `in`, with every SEND replaced by the workflow it sends to
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Decision {
+    Accepted,
+    Rejected,
+    Workflow(String, Box<Decision>),
+    // (filter, what happens if the part matches, what happens otherwise)
+    Filter(Filter, Box<Decision>, Box<Decision>),
+}
+
+impl Decision {
+    pub fn compile(workflows: &HashMap<String, Workflow>) -> Self {
+        Self::compile_result(workflows, &SEND(FIRST_WORKFLOW.to_string()))
+    }
+
+    fn compile_result(workflows: &HashMap<String, Workflow>, result: &ResultWorkflow) -> Self {
+        match result {
+            ACCEPTED => Decision::Accepted,
+            REJECTED => Decision::Rejected,
+            SEND(name) => {
+                let workflow = &workflows[name];
+                // the filters are tried in order, the default applies when none matches
+                let mut decision = Self::compile_result(workflows, &workflow.default);
+                for (filter, res) in workflow.filter.iter().rev() {
+                    let matched = Self::compile_result(workflows, res);
+                    decision = Decision::Filter(*filter, Box::new(matched), Box::new(decision));
+                }
+                Decision::Workflow(name.clone(), Box::new(decision))
+            }
+        }
+    }
+
+    pub fn accepts(&self, part: &Part) -> bool {
+        match self {
+            Decision::Accepted => true,
+            Decision::Rejected => false,
+            Decision::Workflow(_, next) => next.accepts(part),
+            Decision::Filter(filter, matched, unmatched) => {
+                if filter.matches(part) {
+                    matched.accepts(part)
+                } else {
+                    unmatched.accepts(part)
+                }
+            }
+        }
+    }
+
+    // returns: every accepted part of range, with the workflows it went through
+    pub fn accepted_ranges(&self, range: PartRange) -> Vec<(PartRange, Vec<String>)> {
+        let mut res = Vec::new();
+        self.collect_accepted(range, &mut Vec::new(), &mut res);
+        res
+    }
+
+    fn collect_accepted(&self, range: PartRange, chain: &mut Vec<String>, res: &mut Vec<(PartRange, Vec<String>)>) {
+        match self {
+            Decision::Accepted => res.push((range, chain.clone())),
+            Decision::Rejected => {}
+            Decision::Workflow(name, next) => {
+                chain.push(name.clone());
+                next.collect_accepted(range, chain, res);
+                chain.pop();
+            }
+            Decision::Filter(filter, matched, unmatched) => {
+                let (accepted, rejected) = filter.split(&range);
+                if let Some(r) = accepted {
+                    matched.collect_accepted(r, chain, res);
+                }
+                if let Some(r) = rejected {
+                    unmatched.collect_accepted(r, chain, res);
+                }
+            }
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct Part {
+    x: usize,
+    m: usize,
+    a: usize,
+    s: usize,
+}
+
+impl Part {
+    fn new(x: usize, m: usize, a: usize, s: usize) -> Self {
+        Self { x, m, a, s }
+    }
+
+    fn get(&self, category: char) -> usize {
+        match category {
+            'x' => self.x,
+            'm' => self.m,
+            'a' => self.a,
+            's' => self.s,
+            _ => unreachable!(),
+        }
+    }
+}
+
+// index of category in a PartRange
+fn axis(category: char) -> usize {
+    match category {
+        'x' => 0,
+        'm' => 1,
+        'a' => 2,
+        's' => 3,
+        _ => unreachable!(),
+    }
+}
+
+// ranges of x, m, a and s ratings
+pub type PartRange = interval::Box<4>;
+
+// every part, with ratings from 1 to SIZE_OF_PART
+pub fn all_parts() -> PartRange {
+    interval::Box::new(std::array::from_fn(|_| 1..SIZE_OF_PART as i64 + 1))
+}
+
+fn format_part_range(part: &PartRange) -> String {
+    let bounds: Vec<String> = "xmas".chars()
+        .zip(part.ranges.iter())
+        .map(|(category, r)| format!("{category}={}..={}", r.start, r.end - 1))
+        .collect();
+    bounds.join(" ")
+}
+
+// one line per accepted region of the part space, with the workflows leading to it
+pub fn explain(decision: &Decision) -> String {
+    let mut res = String::new();
+    for (range, chain) in decision.accepted_ranges(all_parts()) {
+        res.push_str(&format!("{} ({} parts): {} -> A\n", format_part_range(&range), range.volume(), chain.join(" -> ")));
+    }
+    res
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = (Decision, Vec<Part>);
+
+    fn parse(input: &str) -> Self::Input {
+        let mut map_workflows: HashMap<String, Workflow> = HashMap::new();
+        let mut parts: Vec<Part> = Vec::new();
+
+        let mut at_part: bool = false;
+        for text in input.lines() {
+            if text.is_empty() {
+                at_part = true;
+                continue;
+            }
+
+            if !at_part {
+                // parse px{a<2006:qkq,m>2090:A,rfg}
+                let (name, remainder) = text.split_once('{').unwrap();
+
+                let mut workflow_filters: Vec<(Filter, ResultWorkflow)> = Vec::new();
+                let default;
+
+                let mut split = remainder.split(',');
+                loop {
+                    let s = split.next().unwrap();
+                    if s.contains(':') {
+                        let category = s[0..1].chars().next().unwrap();
+                        let comp = match s[1..2].chars().next().unwrap() {
+                            '<' => Ordering::Less,
+                            '>' => Ordering::Greater,
+                            _ => unreachable!(),
+                        };
+
+                        let mut split = s.split(':');
+                        let val: usize = split.next().unwrap()[2..].parse().unwrap();
+                        let result = ResultWorkflow::from_str(split.next().unwrap());
+
+                        workflow_filters.push((Filter::new(category, comp, val), result));
+                    } else {
+                        default = ResultWorkflow::from_str(&s[..(s.len() - 1)]);
+                        break;
+                    }
+                }
+
+                let mut workflow = Workflow::new(default);
+                for (filter, result) in workflow_filters {
+                    workflow.add_filter(filter, result);
+                }
+
+                map_workflows.insert(name.to_string(), workflow);
+            } else {
+                // parse {x=787,m=2655,a=1222,s=2876}
+                let mut split = text[1..(text.len() - 1)].split(',');
+                let mut x = 0;
+                let mut m = 0;
+                let mut a = 0;
+                let mut s = 0;
+                for i in 0..4 {
+                    let val: usize = split.next().unwrap().split('=').nth(1).unwrap().parse().unwrap();
+                    match i {
+                        0 => x = val,
+                        1 => m = val,
+                        2 => a = val,
+                        3 => s = val,
+                        _ => unreachable!(),
+                    }
+                }
+                parts.push(Part::new(x, m, a, s));
+            }
+        }
+
+        (Decision::compile(&map_workflows), parts)
+    }
+
+    fn part1((decision, parts): &Self::Input) -> Answer {
+        let mut total = 0;
+
+        for part in parts {
+            if decision.accepts(part) {
+                total += part.x + part.m + part.a + part.s;
+            }
+        }
+
+        total.into()
+    }
+
+    fn part2((decision, _): &Self::Input) -> Answer {
+        let total: i64 = decision
+            .accepted_ranges(all_parts())
+            .iter()
+            .map(|(range, _)| range.volume())
+            .sum();
+
+        total.into()
+    }
+}
@@ -0,0 +1,49 @@
+// Prints the module network as a Graphviz graph, with the state of the modules after N presses
+// if asked: `y2023-day20-dot [--presses N] [--input FILE] | dot -Tsvg > circuit.svg`
+use std::env;
+use std::process::ExitCode;
+
+use aoc_common::{load_input, INPUT_FILE_NAME};
+use y2023_day20::Circuit;
+
+const USAGE: &str = "usage: y2023-day20-dot [--presses N] [--input FILE]";
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<(Option<usize>, String), String> {
+    let mut presses = None;
+    let mut file_name = INPUT_FILE_NAME.to_string();
+    while let Some(arg) = args.next() {
+        let value = match arg.as_str() {
+            "--presses" | "--input" => args.next().ok_or(format!("missing value for {arg}"))?,
+            "-h" | "--help" => return Err(USAGE.to_string()),
+            _ => return Err(format!("unknown argument {arg}\n{USAGE}")),
+        };
+        match arg.as_str() {
+            "--presses" => presses = Some(value.parse().map_err(|_| format!("invalid value for {arg}: {value}"))?),
+            _ => file_name = value,
+        }
+    }
+    Ok((presses, file_name))
+}
+
+fn main() -> ExitCode {
+    let (presses, file_name) = match parse_args(env::args().skip(1)) {
+        Ok(args) => args,
+        Err(msg) => {
+            eprintln!("{msg}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let mut circuit = match load_input!(&file_name) {
+        Ok(input) => Circuit::parse(&input),
+        Err(e) => {
+            eprintln!("{e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    for _ in 0..presses.unwrap_or(0) {
+        circuit.press();
+    }
+    print!("{}", circuit.to_dot(presses.is_some()));
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,304 @@
+use std::collections::{HashMap, VecDeque};
+use std::ops::AddAssign;
+use nom::{
+    IResult,
+    bytes::complete::tag,
+    character::complete::alpha1,
+    multi::separated_list0,
+    branch::alt,
+   };
+use crate::Module::*;
+use aoc_common::Answer;
+
+const NUMBER_OF_BUTTON_ACTIVATIONS: usize = 1000;
+const FINAL_MODULE: &str = "rx";
+
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub enum Pulse {
+    High,
+    Low,
+}
+
+#[derive(Clone)]
+enum Module {
+    Broadcast,
+    FlipFlop(String),
+    Conjunction(String),
+}
+
+#[derive(Clone)]
+struct Sender {
+    module: Module,
+    destination: Vec<String>,
+    on: bool,
+    source_map: HashMap<String, Pulse>,
+}
+
+impl Sender {
+    fn new(module: Module, destination: Vec<String>) -> Self {
+        Self { module, destination, on: false, source_map: HashMap::new() }
+    }
+
+    fn name(&self) -> String {
+        match &self.module {
+            Module::Broadcast => "broadcaster",
+            Module::FlipFlop(n) | Module::Conjunction(n) => n,
+        }.to_string()
+    }
+
+    fn add_source(&mut self, source: String) {
+        if let Conjunction(_) = self.module {
+            self.source_map.insert(source, Pulse::Low);
+        }
+    }
+
+    // returns: the pulse sent to every destination, if any
+    fn call(&mut self, pulse: Pulse, from: &str) -> Option<Pulse> {
+        let p = match self.module {
+            Broadcast => pulse,
+            FlipFlop(_) => match pulse {
+                Pulse::High => return None,
+                Pulse::Low => {
+                    self.on = !self.on;
+                    if self.on { Pulse::High } else { Pulse::Low }
+                }
+            },
+            Conjunction(_) => {
+                self.source_map.insert(from.to_string(), pulse);
+                if self.source_map.values().all(|p| *p == Pulse::High) {
+                    Pulse::Low
+                } else {
+                    Pulse::High
+                }
+            },
+        };
+        Some(p)
+    }
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct PulseCount {
+    pub low: usize,
+    pub high: usize,
+}
+
+impl AddAssign for PulseCount {
+    fn add_assign(&mut self, other: Self) {
+        self.low += other.low;
+        self.high += other.high;
+    }
+}
+
+// modules without a line in the input (`output`, `rx`) only receive pulses
+pub struct Circuit {
+    sender_map: HashMap<String, Sender>,
+    presses: usize,
+}
+
+impl Circuit {
+    pub fn parse(input: &str) -> Self {
+        let mut sender_map = HashMap::new();
+        for line in input.lines() {
+            let (_, sender) = parse_line(line).unwrap();
+            sender_map.insert(sender.name(), sender);
+        }
+
+        let mut source_dest: Vec<(String, Vec<String>)> = Vec::new();
+        for (sender_name, sender) in sender_map.iter() {
+            source_dest.push((sender_name.clone(), sender.destination.clone()));
+        }
+        for (source, dest) in source_dest {
+            for d in dest {
+                if let Some(obj) = sender_map.get_mut(&d) {
+                    obj.add_source(source.clone());
+                }
+            }
+        }
+
+        Self { sender_map, presses: 0 }
+    }
+
+    // number of times the button has been pressed
+    pub fn presses(&self) -> usize {
+        self.presses
+    }
+
+    // the modules sending pulses to module
+    pub fn sources(&self, module: &str) -> Vec<String> {
+        let mut res: Vec<String> = self.sender_map.values()
+            .filter(|s| s.destination.iter().any(|d| d == module))
+            .map(|s| s.name())
+            .collect();
+        res.sort();
+        res
+    }
+
+    // pushes the button once
+    // returns: the number of pulses sent, the one from the button included
+    pub fn press(&mut self) -> PulseCount {
+        self.press_watching(|_, _, _| {})
+    }
+
+    // same as `press`, watch is called on every pulse with (source, pulse, destination)
+    pub fn press_watching(&mut self, mut watch: impl FnMut(&str, Pulse, &str)) -> PulseCount {
+        self.presses += 1;
+        let mut count = PulseCount::default();
+        let mut to_send: VecDeque<(String, Pulse, String)> = VecDeque::from([("button".to_string(), Pulse::Low, "broadcaster".to_string())]);
+
+        while let Some((from, pulse, to)) = to_send.pop_front() {
+            watch(&from, pulse, &to);
+            match pulse {
+                Pulse::High => count.high += 1,
+                Pulse::Low => count.low += 1,
+            }
+            let Some(sender) = self.sender_map.get_mut(&to) else { continue };
+            if let Some(p) = sender.call(pulse, &from) {
+                for d in sender.destination.iter() {
+                    to_send.push_back((to.clone(), p, d.clone()));
+                }
+            }
+        }
+        count
+    }
+
+    // the network in the DOT language of Graphviz, one shape by module type
+    // with_state: also writes if flip-flops are on and colours in red the inputs
+    // of conjunctions that last sent a high pulse
+    pub fn to_dot(&self, with_state: bool) -> String {
+        let mut names: Vec<&String> = self.sender_map.keys().collect();
+        names.sort();
+
+        let mut res = String::from("digraph circuit {\n");
+        if with_state {
+            res.push_str(&format!("    label=\"after {} presses\";\n", self.presses));
+        }
+        res.push_str("    button [shape=point];\n    button -> broadcaster;\n");
+        for name in names.iter() {
+            let sender = &self.sender_map[*name];
+            let (shape, mut label) = match sender.module {
+                Broadcast => ("doubleoctagon", name.to_string()),
+                FlipFlop(_) => ("box", format!("%{name}")),
+                Conjunction(_) => ("invtriangle", format!("&{name}")),
+            };
+            if with_state {
+                if let FlipFlop(_) = sender.module {
+                    label.push_str(if sender.on { "\\non" } else { "\\noff" });
+                }
+            }
+            res.push_str(&format!("    {name} [shape={shape}, label=\"{label}\"];\n"));
+        }
+        // modules that only receive pulses
+        let mut receivers: Vec<&String> = self.sender_map.values()
+            .flat_map(|s| s.destination.iter())
+            .filter(|d| !self.sender_map.contains_key(*d))
+            .collect();
+        receivers.sort();
+        receivers.dedup();
+        for name in receivers {
+            res.push_str(&format!("    {name} [shape=doublecircle];\n"));
+        }
+        for name in names {
+            for d in self.sender_map[name].destination.iter() {
+                let high = self.sender_map.get(d).and_then(|s| s.source_map.get(name)) == Some(&Pulse::High);
+                if with_state && high {
+                    res.push_str(&format!("    {name} -> {d} [color=red];\n"));
+                } else {
+                    res.push_str(&format!("    {name} -> {d};\n"));
+                }
+            }
+        }
+        res.push_str("}\n");
+        res
+    }
+}
+
+pub fn part1(input: &str) -> Answer {
+    let mut circuit = Circuit::parse(input);
+
+    let mut total = PulseCount::default();
+    for _ in 0..NUMBER_OF_BUTTON_ACTIVATIONS {
+        total += circuit.press();
+    }
+
+    let res = total.low * total.high;
+    res.into()
+}
+
+// rx is fed by a single conjunction, that sends it a low pulse once all its own inputs
+// last sent a high pulse
+// each of these inputs sends a high pulse periodically, rx gets its low pulse after
+// the least common multiple of the periods
+pub fn part2(input: &str) -> Answer {
+    let mut circuit = Circuit::parse(input);
+
+    let feeders = circuit.sources(FINAL_MODULE);
+    let [feeder] = &feeders[..] else {
+        panic!("{FINAL_MODULE} should have a single conjunction as input, not {feeders:?}");
+    };
+    let inputs = circuit.sources(feeder);
+
+    // presses at which each input sent a high pulse to the feeder, the first two are enough
+    let mut high_presses: HashMap<String, Vec<usize>> = HashMap::new();
+    while inputs.iter().any(|i| high_presses.get(i).is_none_or(|p| p.len() < 2)) {
+        let presses = circuit.presses() + 1;
+        circuit.press_watching(|from, pulse, to| {
+            if to == feeder && pulse == Pulse::High {
+                let p = high_presses.entry(from.to_string()).or_default();
+                if p.last() != Some(&presses) {
+                    p.push(presses);
+                }
+            }
+        });
+    }
+
+    let mut res = 1;
+    for input in inputs {
+        let (first, second) = (high_presses[&input][0], high_presses[&input][1]);
+        let period = second - first;
+        // the LCM only gives the answer if every cycle starts with the first press
+        assert_eq!(first, period, "{input} sends a high pulse at presses {first} and {second}, not a cycle from the start");
+        res = lcm(res, period);
+    }
+
+    res.into()
+}
+
+fn lcm(first: usize, second: usize) -> usize {
+    first * second / gcd(first, second)
+}
+
+fn gcd(first: usize, second: usize) -> usize {
+    if second == 0 { first } else { gcd(second, first % second) }
+}
+
+// parsing
+fn parse_recipients(input: &str) -> IResult<&str, Vec<String>> {
+    let (input, recipients) = separated_list0(tag(", "), alpha1)(input)?;
+    Ok((input, recipients.into_iter().map(|s| s.to_string()).collect()))
+}
+
+fn parse_broadcast(input: &str) -> IResult<&str, Sender> {
+    let (input, _) = tag("broadcaster -> ")(input)?;
+    let (input, recipients) = parse_recipients(input)?;
+    Ok((input, Sender::new(Module::Broadcast, recipients)))
+}
+
+fn parse_flipflop(input: &str) -> IResult<&str, Sender> {
+    let (input, _) = tag("%")(input)?;
+    let (input, id) = alpha1(input)?;
+    let (input, _) = tag(" -> ")(input)?;
+    let (input, recipients) = parse_recipients(input)?;
+    Ok((input, Sender::new(Module::FlipFlop(id.to_string()), recipients)))
+}
+
+fn parse_conjunction(input: &str) -> IResult<&str, Sender> {
+    let (input, _) = tag("&")(input)?;
+    let (input, id) = alpha1(input)?;
+    let (input, _) = tag(" -> ")(input)?;
+    let (input, recipients) = parse_recipients(input)?;
+    Ok((input, Sender::new(Module::Conjunction(id.to_string()), recipients)))
+}
+
+fn parse_line(input: &str) -> IResult<&str, Sender> {
+    alt((parse_broadcast, parse_flipflop, parse_conjunction))(input)
+}
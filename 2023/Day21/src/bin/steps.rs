@@ -0,0 +1,57 @@
+// Number of garden plots reachable in exactly N steps, on the map or repeated infinitely:
+// `y2023-day21-steps [--steps N] [--infinite] [--input FILE]`
+// on the infinite map, large numbers of steps are extrapolated (see `reachable_plots_extrapolated`)
+use std::env;
+use std::process::ExitCode;
+
+use aoc_common::{load_input, INPUT_FILE_NAME};
+use y2023_day21::{Garden, NB_STEPS};
+
+const USAGE: &str = "usage: y2023-day21-steps [--steps N] [--infinite] [--input FILE]";
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<(usize, bool, String), String> {
+    let mut steps = NB_STEPS;
+    let mut infinite = false;
+    let mut file_name = INPUT_FILE_NAME.to_string();
+    while let Some(arg) = args.next() {
+        if arg == "--infinite" {
+            infinite = true;
+            continue;
+        }
+        let value = match arg.as_str() {
+            "--steps" | "--input" => args.next().ok_or(format!("missing value for {arg}"))?,
+            "-h" | "--help" => return Err(USAGE.to_string()),
+            _ => return Err(format!("unknown argument {arg}\n{USAGE}")),
+        };
+        match arg.as_str() {
+            "--steps" => steps = value.parse().map_err(|_| format!("invalid value for {arg}: {value}"))?,
+            _ => file_name = value,
+        }
+    }
+    Ok((steps, infinite, file_name))
+}
+
+fn main() -> ExitCode {
+    let (steps, infinite, file_name) = match parse_args(env::args().skip(1)) {
+        Ok(args) => args,
+        Err(msg) => {
+            eprintln!("{msg}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let garden = match load_input!(&file_name) {
+        Ok(input) => Garden::parse(&input),
+        Err(e) => {
+            eprintln!("{e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let plots = if infinite {
+        garden.reachable_plots_extrapolated(steps)
+    } else {
+        garden.reachable_plots(steps, false)
+    };
+    println!("{plots}");
+    ExitCode::SUCCESS
+}
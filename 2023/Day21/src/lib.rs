@@ -0,0 +1,101 @@
+use std::collections::{HashMap, VecDeque};
+use aoc_common::{Answer, Grid, Point};
+
+pub const NB_STEPS: usize = 64;
+pub const NB_STEPS_INFINITE: usize = 26501365;
+
+pub struct Garden {
+    rocks: Grid<bool>,
+    start: Point,
+}
+
+impl Garden {
+    pub fn parse(input: &str) -> Self {
+        let map = Grid::parse(input, |c| c);
+        let (x, y) = map.position(|c| *c == 'S').expect("no starting point");
+        Self { rocks: map.map(|c| *c == '#'), start: Point::new(x as isize, y as isize) }
+    }
+
+    // infinite: the map is repeated in every direction
+    fn is_rock(&self, p: Point, infinite: bool) -> bool {
+        let (width, height) = (self.rocks.width() as isize, self.rocks.height() as isize);
+        if infinite {
+            self.rocks[(p.x.rem_euclid(width) as usize, p.y.rem_euclid(height) as usize)]
+        } else {
+            p.to_coord().and_then(|c| self.rocks.get(c)).is_none_or(|rock| *rock)
+        }
+    }
+
+    // returns: the number of steps to each garden plot reachable in at most max_steps
+    pub fn distances(&self, max_steps: usize, infinite: bool) -> HashMap<Point, usize> {
+        let mut res = HashMap::from([(self.start, 0)]);
+        let mut to_visit = VecDeque::from([self.start]);
+        while let Some(p) = to_visit.pop_front() {
+            let d = res[&p];
+            if d == max_steps {
+                continue;
+            }
+            for n in p.neighbours4() {
+                if !self.is_rock(n, infinite) && !res.contains_key(&n) {
+                    res.insert(n, d + 1);
+                    to_visit.push_back(n);
+                }
+            }
+        }
+        res
+    }
+
+    // the plots where the elf can be after exactly `steps` steps: going back and forth, it can
+    // end on any plot it reaches in fewer steps of the same parity
+    pub fn reachable_plots(&self, steps: usize, infinite: bool) -> usize {
+        count_reachable(&self.distances(steps, infinite), steps)
+    }
+
+    // Same as `reachable_plots` on the infinite map, for huge numbers of steps
+    //
+    // Once the reachable area covers a few maps, it grows by a map in every direction each
+    // `size` steps: the number of plots is a quadratic function of the number of maps crossed.
+    // It is sampled after `steps % size` steps, then one more map, two more... until the
+    // second differences of the samples settle, and extrapolated from there.
+    pub fn reachable_plots_extrapolated(&self, steps: usize) -> usize {
+        let size = self.rocks.width();
+        assert_eq!(size, self.rocks.height(), "the map should be a square");
+        let remainder = steps % size;
+        let maps_crossed = steps / size;
+
+        let mut nb_samples = 5;
+        loop {
+            if maps_crossed < nb_samples {
+                return self.reachable_plots(steps, true);
+            }
+            let distances = self.distances(remainder + (nb_samples - 1) * size, true);
+            let samples: Vec<usize> = (0..nb_samples).map(|k| count_reachable(&distances, remainder + k * size)).collect();
+            let second_differences: Vec<isize> = samples
+                .windows(3)
+                .map(|w| w[2] as isize + w[0] as isize - 2 * w[1] as isize)
+                .collect();
+            // the first sample from which 3 second differences in a row are the same
+            let settled = second_differences.windows(3).position(|w| w[0] == w[1] && w[1] == w[2]);
+            if let Some(base) = settled {
+                let (f0, d1, d2) = (samples[base], samples[base + 1] - samples[base], second_differences[base] as usize);
+                let k = maps_crossed - base;
+                return f0 + k * d1 + k * (k - 1) / 2 * d2;
+            }
+            nb_samples *= 2;
+        }
+    }
+}
+
+fn count_reachable(distances: &HashMap<Point, usize>, steps: usize) -> usize {
+    distances.values().filter(|d| **d <= steps && **d % 2 == steps % 2).count()
+}
+
+pub fn part1(input: &str) -> Answer {
+    let garden = Garden::parse(input);
+    garden.reachable_plots(NB_STEPS, false).into()
+}
+
+pub fn part2(input: &str) -> Answer {
+    let garden = Garden::parse(input);
+    garden.reachable_plots_extrapolated(NB_STEPS_INFINITE).into()
+}
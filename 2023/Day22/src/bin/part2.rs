@@ -0,0 +1 @@
+aoc_common::main!(y2023_day22::part2);
@@ -0,0 +1,55 @@
+// Prints the bricks once they all fell, in the input format, lowest first:
+// `y2023-day22-settle [--input FILE] > settled.txt`
+// with `--summary`, prints for each brick the ones under and on it, and how many fall without it
+use std::env;
+use std::process::ExitCode;
+
+use aoc_common::{load_input, INPUT_FILE_NAME};
+use y2023_day22::{parse, Stack};
+
+const USAGE: &str = "usage: y2023-day22-settle [--summary] [--input FILE]";
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<(bool, String), String> {
+    let mut summary = false;
+    let mut file_name = INPUT_FILE_NAME.to_string();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--summary" => summary = true,
+            "--input" => file_name = args.next().ok_or(format!("missing value for {arg}"))?,
+            "-h" | "--help" => return Err(USAGE.to_string()),
+            _ => return Err(format!("unknown argument {arg}\n{USAGE}")),
+        }
+    }
+    Ok((summary, file_name))
+}
+
+fn main() -> ExitCode {
+    let (summary, file_name) = match parse_args(env::args().skip(1)) {
+        Ok(args) => args,
+        Err(msg) => {
+            eprintln!("{msg}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let stack = match load_input!(&file_name) {
+        Ok(input) => Stack::settle(parse(&input)),
+        Err(e) => {
+            eprintln!("{e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if !summary {
+        print!("{}", stack.export());
+        return ExitCode::SUCCESS;
+    }
+    let chain_reactions = stack.chain_reactions();
+    for (idx, brick) in stack.bricks().iter().enumerate() {
+        println!("{idx}: {brick} on {:?}, under {:?}, {} falling without it{}",
+            stack.supported_by(idx),
+            stack.supports(idx),
+            chain_reactions[idx],
+            if stack.safe_to_disintegrate(idx) { " (safe)" } else { "" });
+    }
+    ExitCode::SUCCESS
+}
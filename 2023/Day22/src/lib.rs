@@ -0,0 +1,199 @@
+use std::fmt;
+use std::ops::Range;
+use nom::{
+    IResult,
+    bytes::complete::tag,
+    multi::separated_list0,
+    character::complete::i64,
+   };
+use aoc_common::interval;
+use aoc_common::Answer;
+
+const Z: usize = 2;
+const GROUND: i64 = 1; // lowest z of a brick
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Brick {
+    pub cube: interval::Box<3>,
+}
+
+impl Brick {
+    fn new(x_range: Range<i64>, y_range: Range<i64>, z_range: Range<i64>) -> Self {
+        Self { cube: interval::Box::new([x_range, y_range, z_range]) }
+    }
+
+    fn z_range(&self) -> &Range<i64> {
+        &self.cube.ranges[Z]
+    }
+
+    // the (x, y) of the cubes under the brick, seen from above
+    fn footprint(&self) -> impl Iterator<Item = (i64, i64)> + '_ {
+        let [x_range, y_range, _] = &self.cube.ranges;
+        x_range.clone().flat_map(move |x| y_range.clone().map(move |y| (x, y)))
+    }
+}
+
+// same format as the input: 1,0,1~1,2,1
+impl fmt::Display for Brick {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let [x_range, y_range, z_range] = &self.cube.ranges;
+        write!(f, "{},{},{}~{},{},{}",
+            x_range.start,
+            y_range.start,
+            z_range.start,
+            x_range.end - 1,
+            y_range.end - 1,
+            z_range.end - 1)
+    }
+}
+
+// The bricks once they all fell, lowest first, and which ones rest on which
+pub struct Stack {
+    bricks: Vec<Brick>,
+    supports: Vec<Vec<usize>>,     // supports[i]: the bricks resting on i
+    supported_by: Vec<Vec<usize>>, // supported_by[i]: the bricks i rests on, none on the ground
+}
+
+impl Stack {
+    // the bricks fall by increasing altitude, each one stops on the highest of the tops
+    // below it: a height map of the tops, with the brick at the top of each column, is enough
+    pub fn settle(mut bricks: Vec<Brick>) -> Self {
+        bricks.sort_by_key(|brick| brick.z_range().start);
+        let width = bricks.iter().map(|b| b.cube.ranges[0].end).max().unwrap_or(0) as usize;
+        let depth = bricks.iter().map(|b| b.cube.ranges[1].end).max().unwrap_or(0) as usize;
+
+        // (z above the column, brick at the top of the column)
+        let mut tops: Vec<(i64, Option<usize>)> = vec![(GROUND, None); width * depth];
+        let column = |(x, y): (i64, i64)| x as usize * depth + y as usize;
+
+        let mut supports = vec![Vec::new(); bricks.len()];
+        let mut supported_by = vec![Vec::new(); bricks.len()];
+        for (idx, brick) in bricks.iter_mut().enumerate() {
+            let z = brick.footprint().map(|c| tops[column(c)].0).max().unwrap();
+            let mut below: Vec<usize> = brick.footprint()
+                .filter_map(|c| match tops[column(c)] {
+                    (top, Some(other)) if top == z => Some(other),
+                    _ => None,
+                })
+                .collect();
+            below.sort();
+            below.dedup();
+            for other in below.iter() {
+                supports[*other].push(idx);
+            }
+            supported_by[idx] = below;
+
+            // fall
+            *brick = Brick { cube: brick.cube.shifted(Z, z - brick.z_range().start) };
+            for c in brick.footprint() {
+                tops[column(c)] = (brick.z_range().end, Some(idx));
+            }
+        }
+
+        Self { bricks, supports, supported_by }
+    }
+
+    pub fn len(&self) -> usize {
+        self.bricks.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bricks.is_empty()
+    }
+
+    pub fn bricks(&self) -> &[Brick] {
+        &self.bricks
+    }
+
+    pub fn supports(&self, idx: usize) -> &[usize] {
+        &self.supports[idx]
+    }
+
+    pub fn supported_by(&self, idx: usize) -> &[usize] {
+        &self.supported_by[idx]
+    }
+
+    // if no other brick falls without idx: the ones on it rest on others too
+    pub fn safe_to_disintegrate(&self, idx: usize) -> bool {
+        self.supports[idx].iter().all(|other| self.supported_by[*other].len() > 1)
+    }
+
+    // returns: for each brick, the number of other bricks falling without it
+    //
+    // Brick j falls without i when every path from the ground to j goes through i, i.e. i
+    // dominates j in the support graph. The bricks are sorted in a topological order, so the
+    // immediate dominator of a brick is the lowest common ancestor of the bricks under it in
+    // the dominator tree built so far.
+    pub fn chain_reactions(&self) -> Vec<usize> {
+        // None: the ground
+        let mut idom: Vec<Option<usize>> = vec![None; self.len()];
+        let mut depth: Vec<usize> = vec![0; self.len()];
+        for idx in 0..self.len() {
+            let mut below = self.supported_by[idx].iter().map(|b| Some(*b));
+            let first = below.next().flatten();
+            idom[idx] = below.fold(first, |a, b| common_dominator(&idom, &depth, a, b));
+            depth[idx] = idom[idx].map_or(0, |d| depth[d] + 1);
+        }
+
+        // size of the subtrees, from the top
+        let mut res = vec![0; self.len()];
+        for idx in (0..self.len()).rev() {
+            if let Some(d) = idom[idx] {
+                res[d] += res[idx] + 1;
+            }
+        }
+        res
+    }
+
+    // the settled bricks, in the input format
+    pub fn export(&self) -> String {
+        self.bricks.iter().map(|brick| format!("{brick}\n")).collect()
+    }
+}
+
+// the lowest common ancestor of a and b in the dominator tree
+fn common_dominator(idom: &[Option<usize>], depth: &[usize], mut a: Option<usize>, mut b: Option<usize>) -> Option<usize> {
+    while a != b {
+        match (a, b) {
+            (Some(x), Some(y)) if depth[x] >= depth[y] => a = idom[x],
+            (_, Some(y)) => b = idom[y],
+            (Some(x), None) => a = idom[x],
+            (None, None) => unreachable!(),
+        }
+    }
+    a
+}
+
+pub fn parse(input: &str) -> Vec<Brick> {
+    input.lines().map(|line| parse_line(line).unwrap().1).collect()
+}
+
+pub fn part1(input: &str) -> Answer {
+    let stack = Stack::settle(parse(input));
+
+    let res = (0..stack.len()).filter(|i| stack.safe_to_disintegrate(*i)).count();
+
+    res.into()
+}
+
+pub fn part2(input: &str) -> Answer {
+    let stack = Stack::settle(parse(input));
+
+    let res: usize = stack.chain_reactions().iter().sum();
+
+    res.into()
+}
+
+// parsing
+// 1,0,1~1,2,1
+fn parse_xyz(input: &str) -> IResult<&str, (i64, i64, i64)> {
+    let (input, pos) = separated_list0(tag(","), i64)(input)?;
+    Ok((input, (pos[0], pos[1], pos[2])))
+}
+
+fn parse_line(input: &str) -> IResult<&str, Brick> {
+    let (input, (x1, y1, z1)) = parse_xyz(input)?;
+    let (input, _) = tag("~")(input)?;
+    let (input, (x2, y2, z2)) = parse_xyz(input)?;
+    Ok((input, Brick::new(x1..x2+1, y1..y2+1, z1..z2+1)))
+}
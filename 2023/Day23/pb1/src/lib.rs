@@ -0,0 +1,41 @@
+use std::collections::HashMap;
+use aoc_common::graph::Graph;
+use aoc_common::{Answer, Direction, Point};
+
+fn neighbours(pos: Point, map: &HashMap<Point, char>) -> Vec<Point> {
+    let mut neighs: Vec<Point> = pos.neighbours4().collect();
+    neighs.retain(|p| map.get(p).is_some_and(|c| *c != '#'));
+    neighs.retain(|p| {
+        match map.get(p).unwrap() {
+            '.' => true,
+            // slopes can only be entered downhill
+            &slope => *p == pos.step(Direction::from_char(slope).unwrap()),
+        }
+    });
+    neighs
+}
+
+pub fn part1(input: &str) -> Answer {
+    let mut map = HashMap::new();
+    let mut rows = 0;
+    let mut cols = 0;
+
+    for (i, line) in input.lines().enumerate() {
+        rows = i + 1;
+        if rows == 1 { cols = line.len() }
+        for (j, c) in line.chars().enumerate() {
+            map.insert(Point::new(j as isize, i as isize), c);
+        }
+    }
+
+    let starting_pos = Point::new((0..cols).find(|j| *map.get(&Point::new(*j as isize, 0)).unwrap() == '.').unwrap() as isize, 0);
+    let goal = Point::new((0..cols).find(|j| *map.get(&Point::new(*j as isize, (rows-1) as isize)).unwrap() == '.').unwrap() as isize, (rows-1) as isize);
+
+    let (mut graph, nodes) = Graph::from_map(starting_pos, |pos| neighbours(*pos, &map));
+    let (start, goal) = (nodes[&starting_pos], nodes[&goal]);
+    graph.simplify(&[start, goal]);
+
+    let res = graph.longest_path(start, goal).unwrap();
+
+    res.into()
+}
@@ -0,0 +1,88 @@
+use std::collections::HashMap;
+use aoc_common::graph::Graph;
+use aoc_common::{Answer, Point};
+
+fn neighbours(pos: Point, map: &HashMap<Point, char>, rows: usize, cols: usize) -> Vec<Point> {
+    let mut neighs: Vec<Point> = pos.neighbours4().collect();
+    neighs.retain(|p| {
+        p.x >= 0 && p.x < cols as isize && p.y >= 0 && p.y < rows as isize
+    });
+    neighs.retain(|p| *map.get(p).unwrap() != '#');
+    neighs
+}
+
+pub fn part2(input: &str) -> Answer {
+    let mut map = HashMap::new();
+    let mut rows = 0;
+    let mut cols = 0;
+
+    for (i, line) in input.lines().enumerate() {
+        rows = i + 1;
+        if rows == 1 {
+            cols = line.len()
+        }
+        for (j, c) in line.chars().enumerate() {
+            map.insert(Point::new(j as isize, i as isize), c);
+        }
+    }
+
+    let starting_pos = Point::new(
+        (0..cols)
+            .find(|j| *map.get(&Point::new(*j as isize, 0)).unwrap() == '.')
+            .unwrap() as isize,
+        0,
+    );
+    let goal = Point::new(
+        (0..cols)
+            .find(|j| {
+                *map.get(&Point::new(*j as isize, (rows - 1) as isize))
+                    .unwrap()
+                    == '.'
+            })
+            .unwrap() as isize,
+        (rows - 1) as isize,
+    );
+
+    let (mut graph, nodes) = Graph::from_map(starting_pos, |pos| neighbours(*pos, &map, rows, cols));
+    let (start, goal) = (nodes[&starting_pos], nodes[&goal]);
+    if !graph.verify_extremities() {
+        panic!("graph has invalid extremities");
+    }
+    if !graph.verify_symmetry() {
+        panic!("graph is not symmetric");
+    }
+    graph.simplify(&[start, goal]);
+    graph.remove_dead_ends(&[start, goal]);
+
+    let res = graph.longest_path(start, goal).unwrap();
+    res.into()
+}
+
+/*
+Input:
+- # : walls
+- other : path
+
+#.#####################
+#.......#########...###
+#######.#########.#.###
+###.....#.>.>.###.#.###
+###v#####.#v#.###.#.###
+###.>...#.#.#.....#...#
+###v###.#.#.#########.#
+###...#.#.#.......#...#
+#####.#.#.#######.#.###
+#.....#.#.#.......#...#
+#.#####.#.#.#########v#
+#.#...#...#...###...>.#
+#.#.#v#######v###.###v#
+#...#.>.#...>.>.#.###.#
+#####v#.#.###v#.#.###.#
+#.....#...#...#.#.#...#
+#.#########.###.#.#.###
+#...###...#...#...#.###
+###.###.#.###v#####v###
+#...#...#.#.>.>.#.>.###
+#.###.###.#.###.#.#v###
+#.....###...###...#...#
+#####################.#*/
@@ -0,0 +1,180 @@
+use std::cmp::Ordering;
+use nom::{
+    IResult,
+    bytes::complete::tag,
+    multi::separated_list0,
+    character::complete::{i64, space1, space0},
+    sequence::{pair, tuple},
+   };
+use aoc_common::Answer;
+
+const AREA_START: i128 = 200000000000000;
+const AREA_END: i128 = 400000000000000;
+
+// num / den, exact as long as i128 does not overflow: with coordinates around 4e14 and
+// speeds around 1e3, numerators stay below 1e21
+#[derive(Clone, Copy, Debug)]
+struct Rational {
+    num: i128,
+    den: i128, // > 0
+}
+
+impl Rational {
+    fn new(num: i128, den: i128) -> Self {
+        assert_ne!(den, 0);
+        if den < 0 { Self { num: -num, den: -den } } else { Self { num, den } }
+    }
+
+    fn from_int(n: i128) -> Self {
+        Self { num: n, den: 1 }
+    }
+}
+
+impl PartialEq for Rational {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Rational {}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.num * other.den).cmp(&(other.num * self.den))
+    }
+}
+
+// the times a hailstone spends somewhere, from start to end included, None: forever
+#[derive(Clone, Copy, Debug)]
+struct Times {
+    start: Rational,
+    end: Option<Rational>,
+}
+
+impl Times {
+    const FUTURE: Self = Self { start: Rational { num: 0, den: 1 }, end: None };
+    const NEVER: Self = Self { start: Rational { num: 1, den: 1 }, end: Some(Rational { num: 0, den: 1 }) };
+
+    fn is_empty(&self) -> bool {
+        self.end.is_some_and(|end| end < self.start)
+    }
+
+    fn intersect(&self, other: &Self) -> Self {
+        let end = match (self.end, other.end) {
+            (Some(e1), Some(e2)) => Some(e1.min(e2)),
+            (e1, e2) => e1.or(e2),
+        };
+        Self { start: self.start.max(other.start), end }
+    }
+}
+
+struct Line {
+    // X = x + t * dx && Y = y + t * dy
+    x: i128,
+    y: i128,
+    dx: i128,
+    dy: i128,
+}
+
+impl Line {
+    fn new(x: isize, y: isize, dx: isize, dy: isize) -> Self {
+        Self { x: x as i128, y: y as i128, dx: dx as i128, dy: dy as i128 }
+    }
+
+    // times t >= 0 when the hailstone is inside the square area, along both axes
+    fn times_in_area(&self, area_start: i128, area_end: i128) -> Times {
+        let mut res = Times::FUTURE;
+        for (p, d) in [(self.x, self.dx), (self.y, self.dy)] {
+            if d == 0 {
+                if p < area_start || p > area_end {
+                    return Times::NEVER;
+                }
+                continue;
+            }
+            let (t1, t2) = (Rational::new(area_start - p, d), Rational::new(area_end - p, d));
+            res = res.intersect(&Times { start: t1.min(t2), end: Some(t1.max(t2)) });
+        }
+        res
+    }
+
+    // if both hailstones go through the same point of the area in the future, not necessarily
+    // at the same time
+    fn crosses_in_area(&self, other: &Self, area_start: i128, area_end: i128) -> bool {
+        // self at t = other at s:
+        // (x1 - x2) = s * dx2 - t * dx1 and (y1 - y2) = s * dy2 - t * dy1
+        let det = self.dx * other.dy - self.dy * other.dx;
+        let (ex, ey) = (other.x - self.x, other.y - self.y);
+
+        if det == 0 {
+            if ex * self.dy - ey * self.dx != 0 {
+                // parallel
+                return false;
+            }
+            // same line: other starts at time t0 of self, then goes the same way as self or
+            // the opposite one, the paths share a half line or a segment
+            // (self is only looked at from time 0, which bounds the segment)
+            let dot = self.dx * other.dx + self.dy * other.dy;
+            let t0 = Rational::new(ex * self.dx + ey * self.dy, self.dx * self.dx + self.dy * self.dy);
+            let other_path = if dot > 0 {
+                Times { start: t0, end: None }
+            } else {
+                Times { start: Times::FUTURE.start, end: Some(t0) }
+            };
+            return !self.times_in_area(area_start, area_end).intersect(&other_path).is_empty();
+        }
+
+        let t = Rational::new(ex * other.dy - ey * other.dx, det);
+        let s = Rational::new(ex * self.dy - ey * self.dx, det);
+        let zero = Rational::from_int(0);
+        if t < zero || s < zero {
+            return false;
+        }
+        // position of self at t, in the area along both axes
+        [(self.x, self.dx), (self.y, self.dy)].iter().all(|&(p, d)| {
+            let pos = Rational::new(p * t.den + d * t.num, t.den);
+            Rational::from_int(area_start) <= pos && pos <= Rational::from_int(area_end)
+        })
+    }
+}
+
+pub fn part1(input: &str) -> Answer {
+    let mut my_lines = Vec::new();
+
+    for line in input.lines() {
+        let (_, a_line) = parse_line(line).unwrap();
+        my_lines.push(a_line);
+    }
+
+    let mut total = 0;
+    for i in 1..my_lines.len() {
+        let first_line = my_lines.get(i).unwrap();
+        for j in 0..i {
+            let second_line = my_lines.get(j).unwrap();
+            if first_line.crosses_in_area(second_line, AREA_START, AREA_END) {
+                total += 1;
+            }
+        }
+    }
+
+    total.into()
+}
+
+// parsing
+// 19, 13, 30 @ -2,  1, -2
+fn parse_xyz(input: &str) -> IResult<&str, (isize, isize, isize)> {
+    let (input, pos) = separated_list0(pair(tag(","), space1), i64)(input)?;
+    Ok((input, (pos[0] as isize, pos[1] as isize, pos[2] as isize)))
+}
+
+fn parse_line(input: &str) -> IResult<&str, Line> {
+    let (input, (x, y, _)) = parse_xyz(input)?;
+    let (input, _) = tuple((space0, tag("@"), space1))(input)?;
+    let (input, (dx, dy, _)) = parse_xyz(input)?;
+    Ok((input, Line::new(x, y, dx, dy)))
+}
@@ -0,0 +1,159 @@
+use std::ops::{Add, Mul, Sub};
+use nom::{
+    IResult,
+    bytes::complete::tag,
+    multi::separated_list0,
+    character::complete::{i64, space1, space0},
+    sequence::{pair, tuple},
+   };
+use aoc_common::Answer;
+
+// exact: with coordinates around 4e14 and speeds around 1e3, the products below stay
+// around 1e33, far from the limits of i128
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Vec3 {
+    x: i128,
+    y: i128,
+    z: i128,
+}
+
+impl Vec3 {
+    const ZERO: Self = Self { x: 0, y: 0, z: 0 };
+
+    fn new(x: i128, y: i128, z: i128) -> Self {
+        Self { x, y, z }
+    }
+
+    fn dot(&self, other: &Self) -> i128 {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    fn cross(&self, other: &Self) -> Self {
+        Self::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+
+    // None if a coordinate is not a multiple of k
+    fn div_exact(&self, k: i128) -> Option<Self> {
+        if k == 0 || self.x % k != 0 || self.y % k != 0 || self.z % k != 0 {
+            return None;
+        }
+        Some(Self::new(self.x / k, self.y / k, self.z / k))
+    }
+}
+
+impl Add for Vec3 {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl Sub for Vec3 {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl Mul<i128> for Vec3 {
+    type Output = Self;
+    fn mul(self, k: i128) -> Self {
+        Self::new(self.x * k, self.y * k, self.z * k)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Hailstone {
+    // position at time t: position + t * velocity
+    position: Vec3,
+    velocity: Vec3,
+}
+
+impl Hailstone {
+    fn at(&self, t: i128) -> Vec3 {
+        self.position + self.velocity * t
+    }
+
+    // seen from other, that does not move and stays at the origin
+    fn relative_to(&self, other: &Self) -> Self {
+        Self { position: self.position - other.position, velocity: self.velocity - other.velocity }
+    }
+
+    // if both are at the same position at the same time t >= 0
+    fn collides_with(&self, other: &Self) -> bool {
+        let relative = self.relative_to(other);
+        if relative.velocity == Vec3::ZERO {
+            return relative.position == Vec3::ZERO;
+        }
+        // the relative position goes through the origin, in the future
+        relative.position.cross(&relative.velocity) == Vec3::ZERO
+            && relative.position.dot(&relative.velocity) <= 0
+            && relative.position.x.checked_rem(relative.velocity.x).is_none_or(|r| r == 0)
+            && relative.position.y.checked_rem(relative.velocity.y).is_none_or(|r| r == 0)
+            && relative.position.z.checked_rem(relative.velocity.z).is_none_or(|r| r == 0)
+    }
+}
+
+// returns: the rock thrown at an integer time and hitting every hailstone at integer times
+//
+// Seen from the first hailstone, the rock goes through the origin, its trajectory is in the
+// plane holding the origin and the line of another hailstone. The other hailstones cross
+// that plane once, where and when the rock hits them: two of them give its trajectory.
+fn rock_trajectory(hailstones: &[Hailstone]) -> Option<Hailstone> {
+    let first = hailstones.first()?;
+    let relative: Vec<Hailstone> = hailstones.iter().map(|h| h.relative_to(first)).collect();
+
+    // normal of the plane, from a hailstone whose line does not go through the origin
+    let normal = relative[1..]
+        .iter()
+        .map(|h| h.position.cross(&h.velocity))
+        .find(|n| *n != Vec3::ZERO)?;
+
+    // (time, position) of the hits, for the hailstones crossing the plane
+    let mut hits = relative.iter().zip(hailstones.iter()).filter_map(|(r, h)| {
+        let speed = r.velocity.dot(&normal);
+        if speed == 0 {
+            return None;
+        }
+        let distance = -r.position.dot(&normal);
+        (distance % speed == 0).then(|| (distance / speed, h.at(distance / speed)))
+    });
+    let (t1, hit1) = hits.next()?;
+    let (t2, hit2) = hits.find(|(t, _)| *t != t1)?;
+
+    let velocity = (hit2 - hit1).div_exact(t2 - t1)?;
+    let rock = Hailstone { position: hit1 - velocity * t1, velocity };
+    hailstones.iter().all(|h| rock.collides_with(h)).then_some(rock)
+}
+
+pub fn part2(input: &str) -> Answer {
+    let mut hailstones = Vec::new();
+
+    for line in input.lines() {
+        let (_, hailstone) = parse_line(line).unwrap();
+        hailstones.push(hailstone);
+    }
+
+    let rock = rock_trajectory(&hailstones).expect("no rock trajectory hits every hailstone");
+    let total = rock.position.x + rock.position.y + rock.position.z;
+
+    total.into()
+}
+
+// parsing
+// 19, 13, 30 @ -2,  1, -2
+fn parse_xyz(input: &str) -> IResult<&str, Vec3> {
+    let (input, pos) = separated_list0(pair(tag(","), space1), i64)(input)?;
+    Ok((input, Vec3::new(pos[0] as i128, pos[1] as i128, pos[2] as i128)))
+}
+
+fn parse_line(input: &str) -> IResult<&str, Hailstone> {
+    let (input, position) = parse_xyz(input)?;
+    let (input, _) = tuple((space0, tag("@"), space1))(input)?;
+    let (input, velocity) = parse_xyz(input)?;
+    Ok((input, Hailstone { position, velocity }))
+}
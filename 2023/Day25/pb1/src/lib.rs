@@ -0,0 +1,59 @@
+use nom::{
+    bytes::complete::tag, character::complete::alpha1, multi::separated_list1, sequence::tuple,
+    IResult,
+};
+use std::collections::HashMap;
+use aoc_common::graph::{Graph, Node};
+use aoc_common::Answer;
+
+const NB_REMOVE: usize = 3;
+
+// nodes are numbered in their order of appearance
+fn intern<'a>(ids: &mut HashMap<&'a str, Node>, name: &'a str) -> Node {
+    let next_id = ids.len();
+    *ids.entry(name).or_insert(next_id)
+}
+
+pub fn part1(input: &str) -> Answer {
+    let mut graph = Graph::new();
+    let mut ids = HashMap::new();
+
+    for line in input.lines() {
+        let (_, (node, edges)) = parse_line(line).unwrap();
+        let from = intern(&mut ids, node);
+        for edge in edges {
+            let to = intern(&mut ids, edge);
+            graph.add_edge(from, to, 1);
+            graph.add_edge(to, from, 1);
+        }
+    }
+
+    let cut = graph.min_cut().expect("the graph has less than two nodes");
+    if cut.edges.len() != NB_REMOVE {
+        panic!("the minimum cut has {} edges instead of {}", cut.edges.len(), NB_REMOVE);
+    }
+    let (first, second) = cut.partitions;
+    (first.len() * second.len()).into()
+}
+
+// parsing
+/* jqt: rhn xhk nvd
+rsh: frs pzl lsr
+xhk: hfx
+cmg: qnr nvd lhk bvb
+rhn: xhk bvb hfx
+bvb: xhk hfx
+pzl: lsr hfx nvd
+qnr: nvd
+ntq: jqt hfx bvb xhk
+nvd: lhk
+lsr: lhk
+rzs: qnr cmg lsr rsh
+frs: qnr lhk lsr */
+
+fn parse_line(input: &str) -> IResult<&str, (&str, Vec<&str>)> {
+    let (input, (name, _, children)) =
+        tuple((alpha1, tag(": "), separated_list1(tag(" "), alpha1)))(input)?;
+
+    Ok((input, (name, children)))
+}
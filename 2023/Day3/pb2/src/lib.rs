@@ -0,0 +1,83 @@
+use aoc_common::Answer;
+
+struct Part {
+    value: u32,
+    len: u32,
+    x: u32,
+    y: u32, 
+}
+
+impl Part {
+    pub fn add_digit(&mut self , c: char) {
+        self.len += 1;
+        self.value = self.value * 10 + c.to_digit(10).unwrap();
+    }
+}
+
+struct Symbol {
+    x: u32,
+    y: u32,
+}
+
+// infinity norm
+fn next_to_part_symb(p: &Part, s: &Symbol) -> bool {
+    let x_dist = p.x.abs_diff(s.x);
+    if x_dist <= 1 && p.y <= s.y + 1 && p.y+p.len >= s.y {
+        return true;
+    }
+    false
+}
+
+pub fn part2(input: &str) -> Answer {
+    // Parsing
+    let mut parts: Vec<Part> = Vec::new();
+    let mut symbols: Vec<Symbol> = Vec::new();
+
+    for (x, text) in input.lines().enumerate() {
+
+        for (y, char) in (0..).zip(text.chars()) {
+
+            if char.is_ascii_digit() {
+                if let Some(last_part) = parts.last_mut() {
+                    if last_part.x == x as u32 && last_part.y + last_part.len == y {
+                        last_part.add_digit(char);
+                    } else {
+                        let part = Part { value: char.to_digit(10).unwrap(), len: 1, x: x as u32, y };
+                        parts.push(part);
+                    }
+                } else {
+                    let part = Part { value: char.to_digit(10).unwrap(), len: 1, x: x as u32, y };
+                    parts.push(part);
+                }
+            } else if char == '*' { // Gear, the other symbols do not count
+                symbols.push(Symbol{x: x as u32, y});
+            }
+        }
+
+    }
+
+    // Computation
+    let mut total = 0;
+    'sym: for symbol in symbols.iter() {
+        let mut gear_ratio = 1;
+        let mut gear_degree = 0;
+
+        for part in parts.iter() {
+            if next_to_part_symb(part, symbol) {
+                gear_degree += 1;
+                if gear_degree > 2 {
+                    continue 'sym;
+                } else {
+                    gear_ratio *= part.value;
+                }
+            }
+        }
+
+        if gear_degree == 2 {
+            total += gear_ratio;
+        }
+    }
+
+
+    total.into()
+}
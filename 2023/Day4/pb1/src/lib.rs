@@ -0,0 +1,50 @@
+use aoc_common::Answer;
+
+struct Ticket {
+    winning: Vec<u32>,
+    scratched: Vec<u32>,
+}
+
+impl Ticket {
+    pub fn new(w: Vec<u32>, s: Vec<u32>) -> Ticket {
+        Ticket {
+            winning: w,
+            scratched: s,
+        }
+    }
+}
+
+pub fn part1(input: &str) -> Answer {
+    //parsing
+    let mut tickets: Vec<Ticket> = Vec::new();
+
+    for text in input.lines() {
+        
+        let (_, numbers) = text.split_once(':').unwrap();
+        let (win, scratch) = numbers.split_once('|').unwrap();
+
+        let winning = win.split_whitespace().map(|x| x.parse().unwrap()).collect();
+        let scratched = scratch.split_whitespace().map(|x| x.parse().unwrap()).collect();
+
+        let ticket = Ticket::new(winning, scratched);
+        tickets.push(ticket);
+    }
+
+    //computations
+    let mut total: u32 = 0;
+    for ticket in tickets {
+        let mut n = 0;
+        
+        for w in ticket.winning {
+            if ticket.scratched.contains(&w) {
+                n += 1;
+            }
+        }
+
+        if n >= 1 {
+            total += 2_u32.pow(n - 1);
+        }        
+    }
+
+    total.into()
+}
@@ -0,0 +1,64 @@
+use aoc_common::Answer;
+
+struct Ticket {
+    winning: Vec<u32>,
+    scratched: Vec<u32>,
+    number: u32,
+}
+
+impl Ticket {
+    pub fn new(w: Vec<u32>, s: Vec<u32>) -> Ticket {
+        Ticket {
+            winning: w,
+            scratched: s,
+            number: 1,
+        }
+    }
+
+    pub fn add(&mut self, n: u32) {
+        self.number += n;
+    }
+}
+
+pub fn part2(input: &str) -> Answer {
+    //parsing
+    let mut tickets: Vec<Ticket> = Vec::new();
+
+    for text in input.lines() {
+        
+        let (_, numbers) = text.split_once(':').unwrap();
+        let (win, scratch) = numbers.split_once('|').unwrap();
+
+        let winning = win.split_whitespace().map(|x| x.parse().unwrap()).collect();
+        let scratched = scratch.split_whitespace().map(|x| x.parse().unwrap()).collect();
+
+        let ticket = Ticket::new(winning, scratched);
+        tickets.push(ticket);
+    }
+
+    //computations
+    let mut total: u32 = 0;
+    for i in 0..tickets.len() {
+
+        let ticket = tickets.get(i).unwrap();
+
+        let mut n = 0;
+        
+        for w in &ticket.winning {
+            if ticket.scratched.contains(w) {
+                n += 1;
+            }
+        }
+
+        total += ticket.number;
+        let number = ticket.number;
+
+        for j in 1..n+1 {
+            if let Some(next_j) = tickets.get_mut(i+j) {
+                next_j.add(number);
+            }
+        }       
+    }
+
+    total.into()
+}
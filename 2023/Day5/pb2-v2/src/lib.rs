@@ -0,0 +1,55 @@
+use std::ops::Range;
+use aoc_common::Answer;
+
+pub fn part2(input: &str) -> Answer {
+    let mut targets: Vec<u64> = Vec::new();
+    let mut mappings: Vec<Vec<(Range<u64>, u64)>> = Vec::new(); // (Range(start_source, end_source), start_dest)
+
+    for (i, text) in input.lines().enumerate() {
+
+        if i == 0 {
+            targets = text.split(": ").nth(1).unwrap().split(' ').map(|s| s.parse().unwrap()).collect();
+            //println!("{:#?}", targets);
+        } else {
+            if text.is_empty() {
+                continue;
+            } else if text.contains(":") {
+                mappings.push(Vec::new());
+            } else {
+                let nums: Vec<u64> = text.split(' ').map(|s| s.parse().unwrap()).collect();
+                //println!("{} - {}", nums[0], nums[0]+nums[2]);
+                mappings.last_mut().unwrap().push((nums[1]..nums[1]+nums[2], nums[0]));
+            }
+        }
+    }
+
+    let mut new_targets: Vec<u64> = Vec::new(); 
+    
+    for target_range in targets.chunks(2) {
+        for target in target_range[0]..target_range[0]+target_range[1] {
+            new_targets.push(target);
+        }
+        println!("une range passée");
+    }
+
+    println!("finished storing data");
+
+    for i in 0..mappings.len() {
+        // find if a range contains x; convert if needed
+        'x:for x in new_targets.iter_mut() {
+            for (r, y) in mappings.get(i).unwrap().iter() {
+                if r.contains(x) {
+                    //print!("{i} range {}-{}: {x} -> ", r.start, r.end);
+                    *x = *y + *x-r.start;
+                    //println!("{x}");
+                    continue 'x;
+                }
+            }
+        }
+        println!("mapping {} finished", i);
+    }
+
+    let total = new_targets.iter().min().unwrap();
+
+    (*total).into()
+}
@@ -0,0 +1,64 @@
+// Lowest location of any seed ranges, and the seeds reaching a location, with the maps of the
+// input: `y2023-day05-pb2-query [--seeds START LENGTH]... [--location N] [--input FILE]`
+// the seed ranges of the input are used unless some are given
+use std::env;
+use std::process::ExitCode;
+
+use aoc_common::interval::IntervalSet;
+use aoc_common::{load_input, INPUT_FILE_NAME};
+use y2023_day05_pb2::{lowest_location, parse, seeds_reaching};
+
+const USAGE: &str = "usage: y2023-day05-pb2-query [--seeds START LENGTH]... [--location N] [--input FILE]";
+
+struct Args {
+    seeds: Option<IntervalSet>,
+    location: Option<i64>,
+    file_name: String,
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Args, String> {
+    let mut res = Args { seeds: None, location: None, file_name: INPUT_FILE_NAME.to_string() };
+    while let Some(arg) = args.next() {
+        let mut value = || -> Result<String, String> { args.next().ok_or(format!("missing value for {arg}")) };
+        let number = |value: String| -> Result<i64, String> { value.parse().map_err(|_| format!("invalid value for {arg}: {value}")) };
+        match arg.as_str() {
+            "--seeds" => {
+                let start = number(value()?)?;
+                let length = number(value()?)?;
+                res.seeds.get_or_insert_with(IntervalSet::new).insert(start..start + length);
+            }
+            "--location" => res.location = Some(number(value()?)?),
+            "--input" => res.file_name = value()?,
+            "-h" | "--help" => return Err(USAGE.to_string()),
+            _ => return Err(format!("unknown argument {arg}\n{USAGE}")),
+        }
+    }
+    Ok(res)
+}
+
+fn main() -> ExitCode {
+    let args = match parse_args(env::args().skip(1)) {
+        Ok(args) => args,
+        Err(msg) => {
+            eprintln!("{msg}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let (input_seeds, seed_to_location) = match load_input!(&args.file_name) {
+        Ok(input) => parse(&input),
+        Err(e) => {
+            eprintln!("{e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let seeds = args.seeds.unwrap_or(input_seeds);
+
+    match lowest_location(&seed_to_location, &seeds) {
+        Some(location) => println!("Lowest location: {location}"),
+        None => println!("No seeds"),
+    }
+    if let Some(location) = args.location {
+        println!("Seeds reaching location {location}: {}", seeds_reaching(&seed_to_location, &seeds, location));
+    }
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,138 @@
+use std::ops::Range;
+use aoc_common::interval::IntervalSet;
+use aoc_common::Answer;
+
+// A map of the almanac, or several of them composed: every integer of a piece range is
+// moved by the piece delta
+// the pieces are sorted and cover every integer, the ones outside the map ranges
+// have a delta of 0
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PiecewiseMap {
+    pieces: Vec<(Range<i64>, i64)>, // (source range, delta)
+}
+
+impl PiecewiseMap {
+    pub fn identity() -> Self {
+        Self { pieces: vec![(i64::MIN..i64::MAX, 0)] }
+    }
+
+    // ranges: (source range, start of the destination range), as in the almanac
+    pub fn from_ranges(ranges: &[(Range<i64>, i64)]) -> Self {
+        let mut sorted: Vec<&(Range<i64>, i64)> = ranges.iter().filter(|(r, _)| !r.is_empty()).collect();
+        sorted.sort_by_key(|(r, _)| r.start);
+
+        let mut pieces = Vec::new();
+        let mut start = i64::MIN;
+        for (r, dest) in sorted {
+            assert!(start <= r.start, "the source ranges overlap at {}", r.start);
+            if start < r.start {
+                pieces.push((start..r.start, 0));
+            }
+            pieces.push((r.clone(), dest - r.start));
+            start = r.end;
+        }
+        pieces.push((start..i64::MAX, 0));
+        Self { pieces }
+    }
+
+    pub fn pieces(&self) -> &[(Range<i64>, i64)] {
+        &self.pieces
+    }
+
+    pub fn apply(&self, x: i64) -> i64 {
+        let i = self.pieces.partition_point(|(r, _)| r.end <= x);
+        x + self.pieces[i].1
+    }
+
+    // returns: the map applying self, then other
+    pub fn then(&self, other: &Self) -> Self {
+        let mut pieces = Vec::new();
+        for (r, delta) in self.pieces.iter() {
+            let image = r.start + delta..r.end + delta;
+            // the pieces of other cut the image of r, each cut gets both deltas
+            let first = other.pieces.partition_point(|(r2, _)| r2.end <= image.start);
+            for (r2, delta2) in other.pieces[first..].iter().take_while(|(r2, _)| r2.start < image.end) {
+                let inter = image.start.max(r2.start)..image.end.min(r2.end);
+                pieces.push((inter.start - delta..inter.end - delta, delta + delta2));
+            }
+        }
+        pieces.sort_by_key(|(r, _)| r.start);
+
+        // merge the neighbour pieces with the same delta
+        let mut merged: Vec<(Range<i64>, i64)> = Vec::new();
+        for (r, delta) in pieces {
+            match merged.last_mut() {
+                Some((last, last_delta)) if last.end == r.start && *last_delta == delta => last.end = r.end,
+                _ => merged.push((r, delta)),
+            }
+        }
+        Self { pieces: merged }
+    }
+
+    // the values of the integers of set
+    pub fn image(&self, set: &IntervalSet) -> IntervalSet {
+        let mut res = IntervalSet::new();
+        for (r, delta) in self.pieces.iter() {
+            res = res.union(&set.intersection(&IntervalSet::from(r.clone())).shifted(*delta));
+        }
+        res
+    }
+
+    // the integers whose value is in set
+    pub fn preimage(&self, set: &IntervalSet) -> IntervalSet {
+        let mut res = IntervalSet::new();
+        for (r, delta) in self.pieces.iter() {
+            let image = IntervalSet::from(r.start + delta..r.end + delta);
+            res = res.union(&set.intersection(&image).shifted(-delta));
+        }
+        res
+    }
+}
+
+// returns: (the seed ranges, every map from seed to location composed)
+pub fn parse(input: &str) -> (IntervalSet, PiecewiseMap) {
+    let mut seeds = IntervalSet::new();
+    let mut mappings: Vec<Vec<(Range<i64>, i64)>> = Vec::new(); // (Range(start_source, end_source), start_dest)
+
+    for (i, text) in input.lines().enumerate() {
+
+        if i == 0 {
+            let numbers: Vec<i64> = text.split(": ").nth(1).unwrap().split(' ').map(|s| s.parse().unwrap()).collect();
+            for pair in numbers.chunks(2) {
+                seeds.insert(pair[0]..pair[0]+pair[1]);
+            }
+        } else {
+            if text.is_empty() {
+                continue;
+            } else if text.contains(":") {
+                mappings.push(Vec::new());
+            } else {
+                let nums: Vec<i64> = text.split(' ').map(|s| s.parse().unwrap()).collect();
+                mappings.last_mut().unwrap().push((nums[1]..nums[1]+nums[2], nums[0]));
+            }
+        }
+    }
+
+    let seed_to_location = mappings
+        .iter()
+        .fold(PiecewiseMap::identity(), |map, ranges| map.then(&PiecewiseMap::from_ranges(ranges)));
+
+    (seeds, seed_to_location)
+}
+
+pub fn lowest_location(seed_to_location: &PiecewiseMap, seeds: &IntervalSet) -> Option<i64> {
+    seed_to_location.image(seeds).min()
+}
+
+// the seeds in seeds that reach location
+pub fn seeds_reaching(seed_to_location: &PiecewiseMap, seeds: &IntervalSet, location: i64) -> IntervalSet {
+    seed_to_location.preimage(&IntervalSet::from(location..location + 1)).intersection(seeds)
+}
+
+pub fn part2(input: &str) -> Answer {
+    let (seeds, seed_to_location) = parse(input);
+
+    let total = lowest_location(&seed_to_location, &seeds).unwrap();
+
+    total.into()
+}
@@ -0,0 +1,52 @@
+use aoc_common::Answer;
+
+struct Race {
+    time: u64,
+    distance_record: u64,
+}
+
+impl Race {
+
+    fn push_time(&self) -> (u64, u64) { // min, max
+
+        let delta = self.time.pow(2) - 4 * self.distance_record;
+
+        let t1: f32 = (self.time as f32 - (delta as f32).sqrt()) / 2.;
+        let t2: f32 = (self.time as f32 + (delta as f32).sqrt()) / 2.;
+
+        (t1.floor() as u64 + 1, t2.ceil() as u64 - 1)
+
+    }
+
+}
+
+pub fn part2(input: &str) -> Answer {
+    let mut race = Race{time:0, distance_record:0};
+
+    
+    let mut line_iter  = input.lines();
+
+    if let Some(text) = line_iter.next() {
+
+        let time: u64 = text.split(':').nth(1).unwrap().split_whitespace().collect::<Vec<&str>>().join("").parse().unwrap();
+
+        if let Some(text2) = line_iter.next() {
+
+            let dist: u64 = text2.split(':').nth(1).unwrap().split_whitespace().collect::<Vec<&str>>().join("").parse().unwrap();
+
+            race = Race {time, distance_record: dist};
+
+        }
+
+    } else {
+        panic!();
+    }
+
+    
+
+    let (t1, t2) = race.push_time();
+    let total = t2 + 1 - t1;
+    
+
+    total.into()
+}
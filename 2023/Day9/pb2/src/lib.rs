@@ -0,0 +1,82 @@
+use aoc_common::Answer;
+
+struct History {
+    lines: Vec<Vec<i64>>,
+}
+
+impl History {
+    fn new(s: String) -> Self {
+        Self {
+            lines: vec![s.as_str().split(" ").map(|x| x.parse::<i64>().unwrap()).collect()],
+        }
+    }
+
+    fn calc_variations(&mut self) {
+        let mut current_line = self.lines.first().unwrap().clone();        
+
+        while !current_line.iter().all(|&x| x == 0) {
+
+            let mut new_line = Vec::new();
+            for i in 0..current_line.len()-1 {
+
+                new_line.push(current_line.get(i+1).unwrap() - current_line.get(i).unwrap());
+
+            }
+            self.lines.push(new_line.clone());
+            current_line = new_line.clone();
+
+        }
+    }
+
+    fn _predict(&mut self) -> i64 {
+        
+        let mut last_number = 0;
+
+        let mut rev_lines = self.lines.iter_mut().rev();
+        rev_lines.next(); // the first line contains only 0 and is not useful
+
+        for line in rev_lines {
+            last_number += line.last().unwrap();
+            line.push(last_number);
+        }
+
+        last_number
+    }
+
+    fn predict_backward(&mut self) -> i64 {
+        
+        let mut prev_number = 0;
+
+        let mut rev_lines = self.lines.iter_mut().rev();
+        rev_lines.next(); // the first line contains only 0 and is not useful
+
+        for line in rev_lines {
+            prev_number = line.first().unwrap() - prev_number;
+            line.insert(0, prev_number);
+        }
+
+        prev_number
+    }
+
+}
+
+pub fn part2(input: &str) -> Answer {
+    let mut histories = Vec::new();
+
+    // Parsing
+    for text in input.lines() {
+
+        histories.push(History::new(text.to_string()));
+
+    }
+
+    // Computation
+    let mut total = 0;
+
+    for hist in histories.iter_mut() {
+        hist.calc_variations();
+        total += hist.predict_backward();
+    }
+
+    total.into()
+}
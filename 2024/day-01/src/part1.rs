@@ -0,0 +1,24 @@
+use aoc_common::Answer;
+
+pub fn part1(input: &str) -> Answer {
+    let mut list1 = vec![];
+    let mut list2 = vec![];
+
+    for line in input.lines() {
+        let mut split = line.split_whitespace();
+        let (num1, num2) = (split.next().unwrap(), split.next().unwrap());
+        list1.push(num1.parse::<usize>().unwrap());
+        list2.push(num2.parse::<usize>().unwrap());
+    }
+
+    list1.sort();
+    list2.sort();
+
+    let mut result: usize = 0;
+
+    for i in 0..list1.len() {
+        result += list1.get(i).unwrap().abs_diff(*list2.get(i).unwrap());
+    }
+
+    result.into()
+}
@@ -0,0 +1,27 @@
+use std::collections::HashMap;
+use aoc_common::Answer;
+
+fn index_element(map: &mut HashMap<usize, (usize, usize)>, elem1: usize, elem2: usize) {
+    map.entry(elem1).or_insert((0, 0)).0 += 1;
+    map.entry(elem2).or_insert((0, 0)).1 += 1;
+}
+
+pub fn part2(input: &str) -> Answer {
+    let mut map = HashMap::new();
+
+    for line in input.lines() {
+        let mut split = line.split_whitespace();
+        let (num1, num2) = (split.next().unwrap(), split.next().unwrap());
+        index_element(&mut map, num1.parse().unwrap(), num2.parse().unwrap());
+    }
+
+
+    let mut result: usize = 0;
+
+    for key in map.keys() {
+        let (a, b) = map.get(key).unwrap();
+        result += key * a * b; 
+    }
+
+    result.into()
+}
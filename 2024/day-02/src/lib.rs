@@ -0,0 +1,5 @@
+mod part1;
+mod part2;
+
+pub use part1::part1;
+pub use part2::part2;
@@ -0,0 +1,39 @@
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use aoc_common::Answer;
+
+const MIN_DIFF: usize = 1;
+const MAX_DIFF: usize = 3;
+
+fn is_safe(report: Vec<usize>) -> bool {
+    let mut order_set = HashSet::new();
+    for nums in report.windows(2) {
+        let a = nums[0];
+        let b = nums[1];
+        let distance = a.abs_diff(b);
+        if !(MIN_DIFF..=MAX_DIFF).contains(&distance) {
+            return false;
+        }
+        let ordering = a.cmp(&b);
+        if ordering != Ordering::Equal {
+            order_set.insert(ordering);
+            if order_set.len() > 1 {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+pub fn part1(input: &str) -> Answer {
+    let mut result: usize = 0;
+
+    for line in input.lines() {
+        let report = line.split(' ').map(|val| val.parse().unwrap()).collect();
+        if is_safe(report) {
+            result += 1;
+        }
+    }
+
+    result.into()
+}
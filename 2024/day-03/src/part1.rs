@@ -0,0 +1,18 @@
+use regex::Regex;
+use aoc_common::Answer;
+
+pub fn part1(input: &str) -> Answer {
+    let mul_regex = Regex::new(r"mul\([0-9]{1,3},[0-9]{1,3}\)").unwrap();
+
+    let mut result: usize = 0;
+
+    for line in input.lines() {
+        let muls: Vec<&str> = mul_regex.find_iter(line).map(|m| m.as_str()).collect();
+        for mul in muls {
+            let (a, b) = mul.split(&['(', ')']).nth(1).unwrap().split_once(',').unwrap();
+            result += a.parse::<usize>().unwrap() * b.parse::<usize>().unwrap();
+        }
+    }
+
+    result.into()
+}
@@ -0,0 +1,21 @@
+use aoc_common::grid::OFFSETS_8;
+use aoc_common::{Answer, Grid};
+
+const WORD: [char; 4] = ['X', 'M', 'A', 'S'];
+
+pub fn part1(input: &str) -> Answer {
+    let grid = Grid::parse(input, |c| c);
+
+    let mut result: usize = 0;
+
+    // Iterate over all instance of first letter, and read the word in every direction
+    for (start, _) in grid.iter().filter(|(_, letter)| **letter == WORD[0]) {
+        for offset in OFFSETS_8 {
+            if grid.ray(start, offset).map(|pos| grid[pos]).take(WORD.len()).eq(WORD) {
+                result += 1;
+            }
+        }
+    }
+
+    result.into()
+}
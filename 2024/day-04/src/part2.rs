@@ -0,0 +1,27 @@
+use std::collections::HashMap;
+use aoc_common::{Answer, Grid};
+
+const WORD: [char; 3] = ['M', 'A', 'S'];
+
+const DIAGONALS: [(isize, isize); 4] = [(1, 1), (1, -1), (-1, 1), (-1, -1)];
+
+pub fn part2(input: &str) -> Answer {
+    let grid = Grid::parse(input, |c| c);
+
+    let mut count_middle: HashMap<(usize, usize), usize> = HashMap::new();
+
+    // Iterate over all instance of first letter, and read the word in the diagonals
+    for (start, _) in grid.iter().filter(|(_, letter)| **letter == WORD[0]) {
+        for offset in DIAGONALS {
+            let positions: Vec<(usize, usize)> = grid.ray(start, offset).take(WORD.len()).collect();
+            if positions.iter().map(|pos| grid[*pos]).eq(WORD) {
+                *count_middle.entry(positions[WORD.len() / 2]).or_insert(0) += 1;
+            }
+        }
+    }
+
+    // two words crossing on their middle letter
+    let result: usize = count_middle.values().filter(|n| **n == 2).count();
+
+    result.into()
+}
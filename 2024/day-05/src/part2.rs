@@ -0,0 +1,48 @@
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+use aoc_common::Answer;
+
+fn middle_number(list: &[usize]) -> usize {
+    *list.get(list.len() / 2).unwrap()
+}
+
+pub fn part2(input: &str) -> Answer {
+    let mut required_map: HashMap<usize,HashSet<usize>> = HashMap::new();
+
+    let mut result: usize = 0;
+
+    let mut second_part = false;
+    for line in input.lines() {
+        if !second_part {
+            if line.is_empty() {
+                second_part = true;
+            } else {
+                let nums_str = line.split_once('|').unwrap();
+                required_map.entry(nums_str.1.parse().unwrap()).or_default().insert(nums_str.0.parse().unwrap());
+            }
+        } else {
+            let mut visited_nums: Vec<usize> = Vec::new();
+            let mut ordering_error = false;
+            for num_str in line.split(',') {
+                for visited in &visited_nums {
+                    if required_map.contains_key(visited) && required_map.get(visited).unwrap().contains(&num_str.parse().unwrap()) {
+                        ordering_error = true;
+                    }
+                }
+                visited_nums.push(num_str.parse().unwrap());
+            }
+            if ordering_error {
+
+                visited_nums.sort_by(|a, b| if required_map.contains_key(a) && required_map.get(a).unwrap().contains(b) {
+                    Ordering::Greater
+                } else {
+                    Ordering::Less
+                });
+
+                result += middle_number(&visited_nums);
+            }
+        }
+    }
+
+    result.into()
+}
@@ -0,0 +1,7 @@
+mod part1;
+mod part2;
+mod part2_par;
+
+pub use part1::part1;
+pub use part2::part2;
+pub use part2_par::part2_par;
@@ -0,0 +1,75 @@
+use std::collections::HashSet;
+use aoc_common::{Answer, Direction, Point};
+
+// Returns empty optional if the next position is out of the map
+fn next_position(position: Point, direction: Direction, max_lines: usize, max_cols: usize) -> Option<Point> {
+    let next_pos = position.step(direction);
+    if next_pos.x < 0 || next_pos.x > max_cols as isize || next_pos.y < 0 || next_pos.y > max_lines as isize {
+        return None;
+    }
+    Some(next_pos)
+}
+
+// Returns empty optional if out of the map
+fn guard_move(position: Point, direction: Direction, obstacles: &HashSet<Point>, max_lines: usize, max_cols: usize) 
+    -> (Option<Point>, Direction){
+        if let Some(next_position) = next_position(position, direction, max_lines, max_cols) {
+            if obstacles.contains(&next_position) {
+                (Some(position), direction.turn_right())
+            } else {
+                (Some(next_position), direction)
+            }
+        } else {
+            (None, direction)
+        }
+}
+
+pub fn part2(input: &str) -> Answer {
+    let mut max_lines = 0;
+    let mut max_cols = 0;
+
+    let mut obstacles: HashSet<Point> = HashSet::new();
+    let mut original_guard_pos = Point::ORIGIN;
+
+    for (i, line) in input.lines().enumerate() {
+        max_lines = i;
+        max_cols = line.len()-1;
+        line.match_indices('#').map(|(k, _)| k).for_each(|k| { obstacles.insert(Point::new(k as isize, i as isize)); });
+        if line.contains('^') {
+            original_guard_pos = Point::new(line.find('^').unwrap() as isize, i as isize);
+        }
+    }
+
+    let mut guard_pos: Option<Point> = Some(original_guard_pos);
+
+
+    let mut visited_pos = HashSet::new();
+    let mut direction = Direction::North;
+    
+    while let Some(position) = guard_pos {
+        visited_pos.insert(position);
+        (guard_pos, direction) = guard_move(position, direction, &obstacles, max_lines, max_cols);
+    }
+
+    let mut result = 0;
+
+    for obstacle_position in visited_pos {
+        let mut new_obstacles = obstacles.clone();
+        new_obstacles.insert(obstacle_position);
+        let mut visited_state = HashSet::new();
+
+        guard_pos = Some(original_guard_pos);
+        direction = Direction::North;
+
+        while let Some(position) = guard_pos {
+            if visited_state.contains(&(position, direction)) {
+                result += 1;
+                break;
+            }
+            visited_state.insert((position, direction));
+            (guard_pos, direction) = guard_move(position, direction, &new_obstacles, max_lines, max_cols);
+        }
+    }
+
+    result.into()
+}
@@ -0,0 +1,92 @@
+use rayon::prelude::*;
+use aoc_common::{Answer, Solution};
+
+// concatenation only appears in part 2
+const OPERATORS_PART1: [Operator; 2] = [Operator::Add, Operator::Mul];
+const OPERATORS_PART2: [Operator; 3] = [Operator::Add, Operator::Mul, Operator::Concat];
+
+enum Operator {
+    Add,
+    Mul, 
+    Concat
+}
+
+impl Operator {
+    fn inverse(&self, a: isize, b: isize) -> Option<isize> {
+        match self {
+            Self::Add => Some(a - b),
+            Self::Mul => if a % b == 0 {
+                Some (a / b)
+            } else {
+                None
+            },
+            // strip suffix because numbers are taken in reverse order to factorize
+            Self:: Concat => a.to_string().strip_suffix(&b.to_string()).map(|n| n.parse().unwrap())
+        }
+    }
+}
+
+// values: values to combine to get target
+fn feasability(target: isize, values: &[isize], operators: &[Operator]) -> bool {
+    let mut feasable = false;
+    if values.is_empty() {
+        unreachable!("Values is empty")
+    } else if values.len() == 1 {
+        feasable = *values.first().unwrap() == target;
+    } else {
+        let first = values.first().unwrap();
+        for op in operators {
+            if let Some(next_target) = op.inverse(target, *first) {
+                if feasability(next_target, &values[1..], operators) {
+                    feasable = true;
+                    break;
+                }
+            }
+        }
+    }
+    feasable
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    // (target, values in reverse order)
+    type Input = Vec<(isize, Vec<isize>)>;
+
+    fn parse(input: &str) -> Self::Input {
+        let mut inputs: Vec<(isize, Vec<isize>)> = Vec::new();
+
+        for line in input.lines() {
+            let (target, values) = line.split_once(": ").unwrap();
+            let target_number: isize = target.parse().unwrap();
+            let mut values_number: Vec<isize> = values.split_whitespace().map(|n| n.parse().unwrap()).collect();
+            values_number.reverse(); // reverse because operations are processed left to right but solver uses factorization
+            inputs.push((target_number, values_number));
+        }
+        inputs
+    }
+
+    fn part1(inputs: &Self::Input) -> Answer {
+        let result: isize = inputs.iter().map(|(target, values)|
+            if feasability(*target, values, &OPERATORS_PART1) {
+                target
+            } else {
+                &0
+            }
+        ).sum();
+
+        result.into()
+    }
+
+    fn part2(inputs: &Self::Input) -> Answer {
+        let result: isize = inputs.par_iter().map(|(target, values)|
+            if feasability(*target, values, &OPERATORS_PART2) {
+                target
+            } else {
+                &0
+            }
+        ).sum();
+
+        result.into()
+    }
+}
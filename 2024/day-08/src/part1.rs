@@ -0,0 +1,49 @@
+use std::collections::{HashMap, HashSet};
+use aoc_common::{Answer, Point};
+
+fn find_antinodes(a: Point, b: Point) -> (Point, Point) {
+    let vector = b - a;
+    (a - vector, b + vector)
+}
+
+fn within_bounds(p: Point, x_min: isize, x_max: isize, y_min: isize, y_max: isize) -> bool {
+    (x_min..=x_max).contains(&p.x) && (y_min..=y_max).contains(&p.y)
+}
+
+pub fn part1(input: &str) -> Answer {
+    let mut antennas_position: HashMap<char, Vec<Point>> = HashMap::new();
+
+    let mut max_lines = 0;
+    let mut max_cols = 0;
+
+    for (i, line) in input.lines().enumerate() {
+        for (j, c) in line.chars().enumerate() {
+            if c != '.' {
+                antennas_position.entry(c).or_default().push(Point::new(j as isize, i as isize));
+            }
+            max_lines = i;
+            max_cols = j;
+        }
+    }
+
+    let mut antinodes = HashSet::new();
+
+    for key in antennas_position.keys() {
+        let antennas = antennas_position.get(key).unwrap();
+        for a in antennas {
+            for b in antennas {
+                if a != b {
+                    let nodes = find_antinodes(*a, *b);
+                    antinodes.insert(nodes.0);
+                    antinodes.insert(nodes.1);
+                }
+            }
+        }
+    }
+
+    let result: usize = antinodes.iter()
+        .filter(|&&antinode| within_bounds(antinode, 0, max_cols as isize, 0, max_lines as isize))
+        .count();
+
+    result.into()
+}
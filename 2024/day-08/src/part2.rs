@@ -0,0 +1,61 @@
+use std::collections::{HashMap, HashSet};
+use aoc_common::{Answer, Point};
+
+fn find_antinodes(a: Point, b: Point, x_min: isize, x_max: isize, y_min: isize, y_max: isize) -> HashSet<Point> {
+    let mut antinodes = HashSet::new();
+
+    let mut direction = b - a;
+    let mut next_node = b;
+    while within_bounds(next_node, x_min, x_max, y_min, y_max) {
+        antinodes.insert(next_node);
+        next_node += direction;
+    }
+    direction = - direction;
+    next_node = a;
+    while within_bounds(next_node, x_min, x_max, y_min, y_max) {
+        antinodes.insert(next_node);
+        next_node += direction;
+    }
+    antinodes
+}
+
+fn within_bounds(p: Point, x_min: isize, x_max: isize, y_min: isize, y_max: isize) -> bool {
+    (x_min..=x_max).contains(&p.x) && (y_min..=y_max).contains(&p.y)
+}
+
+pub fn part2(input: &str) -> Answer {
+    let mut antennas_position: HashMap<char, Vec<Point>> = HashMap::new();
+
+    let mut max_lines = 0;
+    let mut max_cols = 0;
+
+    for (i, line) in input.lines().enumerate() {
+        for (j, c) in line.chars().enumerate() {
+            if c != '.' {
+                antennas_position.entry(c).or_default().push(Point::new(j as isize, i as isize));
+            }
+            max_lines = i;
+            max_cols = j;
+        }
+    }
+
+    let mut antinodes = HashSet::new();
+
+    for key in antennas_position.keys() {
+        let antennas = antennas_position.get(key).unwrap();
+        for a in antennas {
+            for b in antennas {
+                if a != b {
+                    let nodes = find_antinodes(*a, *b, 0, max_cols as isize, 0, max_lines as isize);
+                    for node in nodes {
+                        antinodes.insert(node);
+                    }
+                }
+            }
+        }
+    }
+
+    let result: usize = antinodes.len();
+
+    result.into()
+}
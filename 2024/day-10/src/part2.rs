@@ -0,0 +1,29 @@
+use aoc_common::{Answer, Grid};
+
+const MAX_HIKING_HEIGHT: usize = 9;
+
+type Position = (usize, usize);
+
+fn ascending_paths(grid: &Grid<usize>, position: Position) -> impl Iterator<Item = Position> + '_ {
+    grid.neighbours4(position)
+        .filter(move |neighbour| grid[*neighbour] == grid[position] + 1)
+}
+
+fn accessible_trail_ends(grid: &Grid<usize>, position: Position) -> Vec<Position> {
+    if grid[position] == MAX_HIKING_HEIGHT {
+        vec![position]
+    } else {
+        ascending_paths(grid, position).flat_map(|neighbour| accessible_trail_ends(grid, neighbour)).collect()
+    }
+}
+
+pub fn part2(input: &str) -> Answer {
+    let grid = Grid::parse(input, |val| val.to_digit(10).unwrap() as usize);
+
+    let result: usize = grid.iter()
+        .filter(|(_, height)| **height == 0)
+        .map(|(trailhead, _)| accessible_trail_ends(&grid, trailhead).len())
+        .sum();
+
+    result.into()
+}
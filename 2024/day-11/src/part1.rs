@@ -0,0 +1,33 @@
+use aoc_common::Answer;
+
+const ITER_NUMBER: usize = 25;
+
+pub fn part1(input: &str) -> Answer {
+    let mut stones: Vec<usize> = Vec::new();
+
+    for line in input.lines() {
+        line.split_whitespace().map(|num| num.parse().unwrap()).for_each(|num| stones.push(num));
+    }
+
+    for _ in 0..ITER_NUMBER {
+        let mut new_stones = vec![];
+
+        for stone in stones {
+            if stone == 0 {
+                new_stones.push(1);
+            } else if stone.to_string().len() % 2 == 0 {
+                let stone_string = stone.to_string();
+                new_stones.push(stone_string[..stone_string.len()/2].parse().unwrap());
+                new_stones.push(stone_string[stone_string.len()/2..].parse().unwrap());
+            } else {
+                new_stones.push(stone * 2024);
+            }
+        }
+
+        stones = new_stones;
+    }
+
+    let result: usize = stones.len();
+
+    result.into()
+}
@@ -0,0 +1,108 @@
+//! Cycle detection for simulations applying the same step again and again: once a state
+//! comes back, the following ones repeat and any number of steps can be skipped.
+//!
+//! States are hashed, each one is kept until the cycle is found.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    /// Number of steps before the first state of the cycle.
+    pub offset: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// Number of steps to the first state equal to the one after `n` steps.
+    pub fn index(&self, n: usize) -> usize {
+        if n < self.offset + self.length {
+            n
+        } else {
+            self.offset + (n - self.offset) % self.length
+        }
+    }
+}
+
+/// The states from `start` until one comes back or after `max_steps` steps, and the cycle
+/// if one was found.
+///
+/// With a cycle, the state after `n` steps is `states[cycle.index(n)]`, for any `n`.
+pub fn find_cycle<S>(start: S, mut step: impl FnMut(&S) -> S, max_steps: usize) -> (Vec<S>, Option<Cycle>)
+where
+    S: Clone + Hash + Eq,
+{
+    let mut first_seen: HashMap<S, usize> = HashMap::from([(start.clone(), 0)]);
+    let mut states = vec![start];
+    while states.len() <= max_steps {
+        let next = step(states.last().unwrap());
+        let steps = states.len();
+        if let Some(&offset) = first_seen.get(&next) {
+            return (states, Some(Cycle { offset, length: steps - offset }));
+        }
+        first_seen.insert(next.clone(), steps);
+        states.push(next);
+    }
+    (states, None)
+}
+
+/// The state after `n` steps from `start`, without simulating them all when the states
+/// cycle before, and the cycle if one was found.
+pub fn fast_forward<S>(start: S, step: impl FnMut(&S) -> S, n: usize) -> (S, Option<Cycle>)
+where
+    S: Clone + Hash + Eq,
+{
+    let (mut states, cycle) = find_cycle(start, step, n);
+    let index = cycle.map_or(n, |c| c.index(n));
+    (states.swap_remove(index), cycle)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0, 1, 2, then 3 to 7 again and again
+    fn step(x: &usize) -> usize {
+        if *x < 3 { x + 1 } else { 3 + (x - 2) % 5 }
+    }
+
+    #[test]
+    fn cycle_after_an_offset() {
+        let (states, cycle) = find_cycle(0, step, 100);
+        assert_eq!(cycle, Some(Cycle { offset: 3, length: 5 }));
+        assert_eq!(states, vec![0, 1, 2, 3, 4, 5, 6, 7]);
+    }
+
+    #[test]
+    fn index_before_in_and_after_the_cycle() {
+        let cycle = Cycle { offset: 3, length: 5 };
+        assert_eq!(cycle.index(0), 0);
+        assert_eq!(cycle.index(2), 2);
+        assert_eq!(cycle.index(7), 7);
+        assert_eq!(cycle.index(8), 3);
+        assert_eq!(cycle.index(1_000_000_001), 6);
+
+        let (states, _) = find_cycle(0, step, 100);
+        let mut x = 0;
+        for n in 0..50 {
+            assert_eq!(states[cycle.index(n)], x, "after {n} steps");
+            x = step(&x);
+        }
+    }
+
+    #[test]
+    fn fast_forward_through_the_cycle() {
+        assert_eq!(fast_forward(0, step, 20), (5, Some(Cycle { offset: 3, length: 5 })));
+        // stops before the cycle comes back
+        assert_eq!(fast_forward(0, step, 2), (2, None));
+        assert_eq!(fast_forward(0, step, 1_000_000_001).0, 6);
+    }
+
+    #[test]
+    fn no_cycle_within_max_steps() {
+        let (states, cycle) = find_cycle(0, |x| x + 1, 10);
+        assert_eq!(cycle, None);
+        assert_eq!(states.len(), 11);
+        assert_eq!(fast_forward(0, |x| x + 1, 10), (10, None));
+    }
+}
@@ -0,0 +1,510 @@
+//! Weighted directed graph over `usize` nodes, with the contractions used to turn a maze
+//! into a small graph and a longest simple path search.
+//!
+//! An undirected graph is a directed one where every edge has its symmetric.
+
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::hash::Hash;
+
+pub type Node = usize;
+
+/// A cut of an undirected graph: removing `edges` splits its nodes into `partitions`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Cut {
+    pub weight: usize,
+    pub edges: Vec<(Node, Node)>,
+    pub partitions: (Vec<Node>, Vec<Node>),
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Graph {
+    edges_from: HashMap<Node, Vec<(usize, Node)>>, // v contains (distance, node) tuples
+}
+
+impl Graph {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The graph of every position reachable from `start`, adjacent positions connected with an
+    /// edge of weight 1. Also returns the node of each position.
+    pub fn from_map<P, I>(start: P, mut neighbours: impl FnMut(&P) -> I) -> (Self, HashMap<P, Node>)
+    where
+        P: Clone + Hash + Eq,
+        I: IntoIterator<Item = P>,
+    {
+        let mut graph = Self::new();
+        let mut nodes = HashMap::from([(start.clone(), 0)]);
+        graph.add_node(0);
+        let mut to_visit = vec![start];
+        while let Some(pos) = to_visit.pop() {
+            let node = nodes[&pos];
+            for neigh in neighbours(&pos) {
+                let next_node = nodes.len();
+                let neigh_node = *nodes.entry(neigh.clone()).or_insert_with(|| {
+                    to_visit.push(neigh);
+                    next_node
+                });
+                graph.add_node(neigh_node);
+                graph.add_edge(node, neigh_node, 1);
+            }
+        }
+        (graph, nodes)
+    }
+
+    pub fn add_node(&mut self, node: Node) {
+        self.edges_from.entry(node).or_default();
+    }
+
+    pub fn add_edge(&mut self, from: Node, to: Node, distance: usize) {
+        self.add_node(to);
+        self.edges_from.entry(from).or_default().push((distance, to));
+    }
+
+    pub fn len(&self) -> usize {
+        self.edges_from.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.edges_from.is_empty()
+    }
+
+    pub fn contains(&self, node: Node) -> bool {
+        self.edges_from.contains_key(&node)
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = Node> + '_ {
+        self.edges_from.keys().copied()
+    }
+
+    /// `(distance, node)` of every edge leaving `node`.
+    pub fn edges_from(&self, node: Node) -> &[(usize, Node)] {
+        self.edges_from.get(&node).map_or(&[], Vec::as_slice)
+    }
+
+    /// If both ends of every edge are in the graph.
+    pub fn verify_extremities(&self) -> bool {
+        self.edges_from.values().flatten().all(|(_, neigh)| self.contains(*neigh))
+    }
+
+    /// If every edge has its symmetric, with the same distance.
+    pub fn verify_symmetry(&self) -> bool {
+        self.edges_from.iter().all(|(node, neighs)| {
+            neighs.iter().all(|(dist, neigh)| self.edges_from(*neigh).contains(&(*dist, *node)))
+        })
+    }
+
+    // nodes with an edge to or from node
+    fn adjacent(&self, node: Node, edges_to: &HashMap<Node, Vec<(usize, Node)>>) -> HashSet<Node> {
+        let to = self.edges_from(node).iter().map(|(_, n)| *n);
+        let from = edges_to.get(&node).into_iter().flatten().map(|(_, n)| *n);
+        to.chain(from).collect()
+    }
+
+    fn edges_to(&self) -> HashMap<Node, Vec<(usize, Node)>> {
+        let mut edges_to: HashMap<Node, Vec<(usize, Node)>> = HashMap::new();
+        for (node, neighs) in self.edges_from.iter() {
+            for (dist, neigh) in neighs {
+                edges_to.entry(*neigh).or_default().push((*dist, *node));
+            }
+        }
+        edges_to
+    }
+
+    fn remove_node(&mut self, node: Node, edges_to: &mut HashMap<Node, Vec<(usize, Node)>>) {
+        for (_, neigh) in self.edges_from.remove(&node).unwrap_or_default() {
+            if let Some(v) = edges_to.get_mut(&neigh) {
+                v.retain(|(_, n)| *n != node);
+            }
+        }
+        for (_, neigh) in edges_to.remove(&node).unwrap_or_default() {
+            if let Some(v) = self.edges_from.get_mut(&neigh) {
+                v.retain(|(_, n)| *n != node);
+            }
+        }
+    }
+
+    /// Removes the nodes connected to exactly two other nodes, except the ones in `keep`. Each
+    /// path going through a removed node is replaced with an edge as long as its two edges.
+    pub fn simplify(&mut self, keep: &[Node]) {
+        let mut edges_to = self.edges_to();
+        let candidates: Vec<Node> = self.nodes().filter(|node| !keep.contains(node)).collect();
+        for node in candidates {
+            let adjacent = self.adjacent(node, &edges_to);
+            if adjacent.len() != 2 || adjacent.contains(&node) {
+                continue;
+            }
+            let ins = edges_to.get(&node).cloned().unwrap_or_default();
+            let outs = self.edges_from(node).to_vec();
+            self.remove_node(node, &mut edges_to);
+            for &(dist1, from) in ins.iter() {
+                for &(dist2, to) in outs.iter() {
+                    if from != to {
+                        self.edges_from.get_mut(&from).unwrap().push((dist1 + dist2, to));
+                        edges_to.entry(to).or_default().push((dist1 + dist2, from));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Removes the nodes connected to a single other node, except the ones in `keep`, until
+    /// there is none left.
+    pub fn remove_dead_ends(&mut self, keep: &[Node]) {
+        let mut edges_to = self.edges_to();
+        let mut to_check: Vec<Node> = self.nodes().collect();
+        while let Some(node) = to_check.pop() {
+            if keep.contains(&node) || !self.contains(node) {
+                continue;
+            }
+            let adjacent = self.adjacent(node, &edges_to);
+            if adjacent.len() <= 1 {
+                self.remove_node(node, &mut edges_to);
+                to_check.extend(adjacent);
+            }
+        }
+    }
+
+    /// Cut of minimal total weight of an undirected graph (every edge has its symmetric), with
+    /// the Stoer-Wagner algorithm. `None` if the graph has less than two nodes.
+    pub fn min_cut(&self) -> Option<Cut> {
+        let nodes: Vec<Node> = self.nodes().collect();
+        if nodes.len() < 2 {
+            return None;
+        }
+        let index: HashMap<Node, usize> = nodes.iter().enumerate().map(|(i, n)| (*n, i)).collect();
+
+        // weights between the merged nodes, and the original nodes each of them contains
+        let mut weights: Vec<HashMap<usize, usize>> = vec![HashMap::new(); nodes.len()];
+        for (i, node) in nodes.iter().enumerate() {
+            for (dist, neigh) in self.edges_from(*node) {
+                if *neigh != *node {
+                    *weights[i].entry(index[neigh]).or_default() += dist;
+                }
+            }
+        }
+        let mut merged: Vec<Vec<usize>> = (0..nodes.len()).map(|i| vec![i]).collect();
+        let mut left: Vec<usize> = (0..nodes.len()).collect();
+
+        let mut best: Option<(usize, Vec<usize>)> = None;
+        while left.len() > 1 {
+            // minimum cut phase: add the most tightly connected node until all are added,
+            // the cut between the last one and the others is a candidate
+            let mut connection = vec![0; nodes.len()];
+            let mut added = vec![false; nodes.len()];
+            let mut queue = BinaryHeap::from([(0, left[0])]);
+            let (mut s, mut t) = (left[0], left[0]);
+            let mut cut_of_phase = 0;
+            while let Some((w, node)) = queue.pop() {
+                if added[node] || w != connection[node] {
+                    continue;
+                }
+                added[node] = true;
+                (s, t, cut_of_phase) = (t, node, w);
+                for (&neigh, &dist) in weights[node].iter() {
+                    if !added[neigh] {
+                        connection[neigh] += dist;
+                        queue.push((connection[neigh], neigh));
+                    }
+                }
+            }
+            if left.iter().any(|&n| !added[n]) {
+                // not connected, nothing to cut
+                let side = left.iter().filter(|&&n| added[n]).flat_map(|&n| merged[n].clone()).collect();
+                best = Some((0, side));
+                break;
+            }
+            if best.as_ref().is_none_or(|(w, _)| cut_of_phase < *w) {
+                best = Some((cut_of_phase, merged[t].clone()));
+            }
+
+            // merge t into s
+            let t_weights = std::mem::take(&mut weights[t]);
+            for (neigh, dist) in t_weights {
+                weights[neigh].remove(&t);
+                if neigh != s {
+                    *weights[s].entry(neigh).or_default() += dist;
+                    *weights[neigh].entry(s).or_default() += dist;
+                }
+            }
+            let t_merged = std::mem::take(&mut merged[t]);
+            merged[s].extend(t_merged);
+            left.retain(|&n| n != t);
+        }
+
+        let (weight, side) = best?;
+        let mut in_side = vec![false; nodes.len()];
+        for &i in side.iter() {
+            in_side[i] = true;
+        }
+        let (first, second): (Vec<Node>, Vec<Node>) = nodes.iter().partition(|n| in_side[index[n]]);
+        let edges = first
+            .iter()
+            .flat_map(|&node| self.edges_from(node).iter().filter(|(_, n)| !in_side[index[n]]).map(move |(_, n)| (node, *n)))
+            .collect();
+        Some(Cut { weight, edges, partitions: (first, second) })
+    }
+
+    /// Length of the longest path from `start` to `goal` that does not go twice through the
+    /// same node, `None` if `goal` cannot be reached or one of them is not in the graph.
+    ///
+    /// Panics if the graph has more than 128 nodes: `simplify` it first.
+    pub fn longest_path(&self, start: Node, goal: Node) -> Option<usize> {
+        let mut nodes: Vec<Node> = self.nodes().collect();
+        nodes.sort();
+        assert!(nodes.len() <= 128, "{} nodes do not fit in a bitmask", nodes.len());
+        let index: HashMap<Node, usize> = nodes.iter().enumerate().map(|(i, n)| (*n, i)).collect();
+
+        // edges between node indices, the longest first so that good paths are found early
+        let edges: Vec<Vec<(usize, usize)>> = nodes
+            .iter()
+            .map(|node| {
+                let mut neighs: Vec<(usize, usize)> = self.edges_from(*node).iter().map(|(d, n)| (*d, index[n])).collect();
+                neighs.sort_by(|a, b| b.cmp(a));
+                neighs
+            })
+            .collect();
+        // a path enters each node at most once, through its longest incoming edge at best
+        let mut best_entry = vec![0; nodes.len()];
+        let mut successors = vec![0; nodes.len()];
+        for (i, neighs) in edges.iter().enumerate() {
+            for &(dist, n) in neighs {
+                best_entry[n] = best_entry[n].max(dist);
+                successors[i] |= 1 << n;
+            }
+        }
+
+        let mut search = LongestPath {
+            edges,
+            best_entry,
+            successors,
+            goal: *index.get(&goal)?,
+            best: None,
+            seen: HashMap::new(),
+        };
+        let start = *index.get(&start)?;
+        search.explore(start, 1 << start, 0);
+        search.best
+    }
+}
+
+struct LongestPath {
+    edges: Vec<Vec<(usize, usize)>>,
+    best_entry: Vec<usize>,
+    successors: Vec<u128>, // bitmask of the nodes reachable in one step
+    goal: usize,
+    best: Option<usize>,
+    // longest distance found to reach a node with a given set of nodes still reachable from it,
+    // the rest of the path only depends on these two
+    seen: HashMap<(usize, u128), usize>,
+}
+
+impl LongestPath {
+    // bitmask of the unvisited nodes that can be reached from node
+    fn reachable(&self, node: usize, visited: u128) -> u128 {
+        let mut reached = 0;
+        let mut frontier = self.successors[node] & !visited;
+        while frontier != 0 {
+            reached |= frontier;
+            let mut next = 0;
+            let mut bits = frontier;
+            while bits != 0 {
+                next |= self.successors[bits.trailing_zeros() as usize];
+                bits &= bits - 1;
+            }
+            frontier = next & !visited & !reached;
+        }
+        reached
+    }
+
+    fn explore(&mut self, node: usize, visited: u128, distance: usize) {
+        if node == self.goal {
+            self.best = self.best.max(Some(distance));
+            return;
+        }
+        let reachable = self.reachable(node, visited);
+        if reachable & (1 << self.goal) == 0 {
+            return;
+        }
+
+        // branch and bound: even going through every reachable node cannot beat the best path
+        let mut bound = 0;
+        let mut bits = reachable;
+        while bits != 0 {
+            bound += self.best_entry[bits.trailing_zeros() as usize];
+            bits &= bits - 1;
+        }
+        if self.best.is_some_and(|best| distance + bound <= best) {
+            return;
+        }
+
+        match self.seen.entry((node, reachable)) {
+            Entry::Occupied(e) if *e.get() >= distance => return,
+            Entry::Occupied(mut e) => {
+                e.insert(distance);
+            }
+            Entry::Vacant(e) => {
+                e.insert(distance);
+            }
+        }
+
+        for i in 0..self.edges[node].len() {
+            let (dist, neigh) = self.edges[node][i];
+            if reachable & (1 << neigh) != 0 {
+                self.explore(neigh, visited | (1 << neigh), distance + dist);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn undirected(edges: &[(Node, Node, usize)]) -> Graph {
+        let mut graph = Graph::new();
+        for &(a, b, dist) in edges {
+            graph.add_edge(a, b, dist);
+            graph.add_edge(b, a, dist);
+        }
+        graph
+    }
+
+    fn clique(nodes: &[Node]) -> Vec<(Node, Node, usize)> {
+        let mut res = Vec::new();
+        for (i, &a) in nodes.iter().enumerate() {
+            for &b in &nodes[i + 1..] {
+                res.push((a, b, 1));
+            }
+        }
+        res
+    }
+
+    // the partitions sorted, the side of node 0 first, and the edges going from that side
+    fn normalized(cut: Cut) -> Cut {
+        let (mut first, mut second) = cut.partitions;
+        let mut edges = cut.edges;
+        if !first.contains(&0) {
+            (first, second) = (second, first);
+            edges = edges.into_iter().map(|(a, b)| (b, a)).collect();
+        }
+        first.sort();
+        second.sort();
+        edges.sort();
+        Cut { weight: cut.weight, edges, partitions: (first, second) }
+    }
+
+    fn cut(weight: usize, edges: &[(Node, Node)], first: &[Node], second: &[Node]) -> Cut {
+        Cut { weight, edges: edges.to_vec(), partitions: (first.to_vec(), second.to_vec()) }
+    }
+
+    fn sorted_edges(graph: &Graph, node: Node) -> Vec<(usize, Node)> {
+        let mut edges = graph.edges_from(node).to_vec();
+        edges.sort();
+        edges
+    }
+
+    fn sorted_nodes(graph: &Graph) -> Vec<Node> {
+        let mut nodes: Vec<Node> = graph.nodes().collect();
+        nodes.sort();
+        nodes
+    }
+
+    #[test]
+    fn simplify_corridors() {
+        // 1 is in a corridor between 0 and the crossing 2, 3 and 4 are dead ends
+        let mut graph = undirected(&[(0, 1, 1), (1, 2, 2), (2, 3, 1), (2, 4, 5)]);
+        graph.simplify(&[0]);
+        assert_eq!(sorted_nodes(&graph), vec![0, 2, 3, 4]);
+        assert_eq!(sorted_edges(&graph, 0), vec![(3, 2)]);
+        assert_eq!(sorted_edges(&graph, 2), vec![(1, 3), (3, 0), (5, 4)]);
+        assert!(graph.verify_symmetry());
+
+        // a one-way corridor stays one-way
+        let mut graph = Graph::new();
+        graph.add_edge(0, 1, 1);
+        graph.add_edge(1, 2, 2);
+        graph.simplify(&[0, 2]);
+        assert_eq!(sorted_nodes(&graph), vec![0, 2]);
+        assert_eq!(sorted_edges(&graph, 0), vec![(3, 2)]);
+        assert_eq!(sorted_edges(&graph, 2), vec![]);
+    }
+
+    #[test]
+    fn remove_dead_ends_until_none_left() {
+        // 4 and 5 lead nowhere, the loop 1, 2, 6 stays
+        let mut graph = undirected(&[(0, 1, 1), (1, 2, 1), (2, 3, 1), (1, 4, 1), (4, 5, 1), (2, 6, 1), (6, 1, 1)]);
+        graph.remove_dead_ends(&[0, 3]);
+        assert_eq!(sorted_nodes(&graph), vec![0, 1, 2, 3, 6]);
+        assert_eq!(sorted_edges(&graph, 1), vec![(1, 0), (1, 2), (1, 6)]);
+        assert!(graph.verify_extremities());
+    }
+
+    // one-way in from 0 and out to 5, around a diamond between 1 and 4 with a shortcut
+    fn diamond(two_ways_shortcut: bool) -> Graph {
+        let mut graph = undirected(&[(1, 2, 5), (1, 3, 2), (2, 4, 3), (3, 4, 4)]);
+        graph.add_edge(0, 1, 1);
+        graph.add_edge(4, 5, 1);
+        graph.add_edge(3, 2, 2);
+        if two_ways_shortcut {
+            graph.add_edge(2, 3, 2);
+        }
+        graph
+    }
+
+    #[test]
+    fn longest_path_around_the_diamond() {
+        // 0, 1, 2, 3, 4, 5
+        assert_eq!(diamond(true).longest_path(0, 5), Some(13));
+        // 0, 1, 2, 4, 5: the shortcut cannot be taken from 2 to 3
+        assert_eq!(diamond(false).longest_path(0, 5), Some(10));
+        assert_eq!(diamond(true).longest_path(1, 1), Some(0));
+    }
+
+    #[test]
+    fn longest_path_against_one_way_edges() {
+        assert_eq!(diamond(true).longest_path(5, 0), None);
+        assert_eq!(diamond(true).longest_path(4, 0), None);
+    }
+
+    #[test]
+    fn longest_path_out_of_the_graph() {
+        assert_eq!(diamond(true).longest_path(0, 9), None);
+        assert_eq!(diamond(true).longest_path(9, 0), None);
+    }
+
+    #[test]
+    fn min_cut_of_two_cliques_joined_by_one_edge() {
+        let mut edges = clique(&[0, 1, 2, 3]);
+        edges.extend(clique(&[4, 5, 6, 7]));
+        edges.push((3, 4, 1));
+        let min_cut = undirected(&edges).min_cut().unwrap();
+        assert_eq!(normalized(min_cut), cut(1, &[(3, 4)], &[0, 1, 2, 3], &[4, 5, 6, 7]));
+    }
+
+    #[test]
+    fn min_cut_of_two_cliques_joined_by_two_edges() {
+        let mut edges = clique(&[0, 1, 2, 3]);
+        edges.extend(clique(&[4, 5, 6, 7]));
+        edges.extend([(2, 5, 1), (3, 4, 1)]);
+        let min_cut = undirected(&edges).min_cut().unwrap();
+        assert_eq!(normalized(min_cut), cut(2, &[(2, 5), (3, 4)], &[0, 1, 2, 3], &[4, 5, 6, 7]));
+    }
+
+    #[test]
+    fn min_cut_of_a_disconnected_graph() {
+        let mut edges = clique(&[0, 1, 2]);
+        edges.extend(clique(&[3, 4, 5]));
+        let min_cut = undirected(&edges).min_cut().unwrap();
+        assert_eq!(normalized(min_cut), cut(0, &[], &[0, 1, 2], &[3, 4, 5]));
+    }
+
+    #[test]
+    fn min_cut_needs_two_nodes() {
+        assert_eq!(Graph::new().min_cut(), None);
+        let mut graph = Graph::new();
+        graph.add_node(0);
+        assert_eq!(graph.min_cut(), None);
+    }
+}
@@ -0,0 +1,270 @@
+//! Dense two dimensional grid, stored row by row in a single vector.
+//!
+//! Cells are addressed by `(x, y)`: `x` is the column and `y` the row, `(0, 0)` is the
+//! top left corner of the input.
+
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+pub type Coord = (usize, usize);
+
+/// Offsets of the 4 orthogonal neighbours, clockwise from the one above.
+pub const OFFSETS_4: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+/// Offsets of the 8 neighbours, clockwise from the one above.
+pub const OFFSETS_8: [(isize, isize); 8] = [(0, -1), (1, -1), (1, 0), (1, 1), (0, 1), (-1, 1), (-1, 0), (-1, -1)];
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(cells.len(), width * height, "a {width}x{height} grid needs {} cells", width * height);
+        Self { width, height, cells }
+    }
+
+    /// Builds a grid from the lines of `input`, each character going through `cell`.
+    ///
+    /// Panics if the lines do not all have the same length.
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> T) -> Self {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::with_capacity(input.len());
+        for line in input.lines() {
+            let before = cells.len();
+            cells.extend(line.chars().map(&mut cell));
+            let line_width = cells.len() - before;
+            match width {
+                None => width = Some(line_width),
+                Some(w) => assert_eq!(w, line_width, "line {} is {line_width} wide instead of {w}", height + 1),
+            }
+            height += 1;
+        }
+        Self::new(width.unwrap_or(0), height, cells)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn contains(&self, (x, y): (isize, isize)) -> bool {
+        x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height
+    }
+
+    pub fn get(&self, (x, y): Coord) -> Option<&T> {
+        if x < self.width && y < self.height {
+            Some(&self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, (x, y): Coord) -> Option<&mut T> {
+        if x < self.width && y < self.height {
+            Some(&mut self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    /// The cell `(dx, dy)` away from `(x, y)`, if it is still in the grid.
+    pub fn step(&self, (x, y): Coord, (dx, dy): (isize, isize)) -> Option<Coord> {
+        let next = (x as isize + dx, y as isize + dy);
+        if self.contains(next) {
+            Some((next.0 as usize, next.1 as usize))
+        } else {
+            None
+        }
+    }
+
+    pub fn neighbours4(&self, coord: Coord) -> impl Iterator<Item = Coord> + '_ {
+        OFFSETS_4.iter().filter_map(move |&offset| self.step(coord, offset))
+    }
+
+    pub fn neighbours8(&self, coord: Coord) -> impl Iterator<Item = Coord> + '_ {
+        OFFSETS_8.iter().filter_map(move |&offset| self.step(coord, offset))
+    }
+
+    /// Coordinates from `start` (included) moving by `offset` until leaving the grid.
+    pub fn ray(&self, start: Coord, offset: (isize, isize)) -> impl Iterator<Item = Coord> + '_ {
+        std::iter::successors(self.get(start).map(|_| start), move |&coord| self.step(coord, offset))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {x} out of a grid {} wide", self.width);
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Diagonals going down to the right, starting from the bottom left corner.
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let starts = (0..self.height).rev().map(|y| (0, y)).chain((1..self.width).map(|x| (x, 0)));
+        starts.map(|start| self.ray(start, (1, 1)).map(|coord| &self[coord]))
+    }
+
+    /// Diagonals going down to the left, starting from the top left corner.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let last_x = self.width.saturating_sub(1);
+        let starts = (0..self.width).map(|x| (x, 0)).chain((1..self.height).map(move |y| (last_x, y)));
+        starts.map(|start| self.ray(start, (-1, 1)).map(|coord| &self[coord]))
+    }
+
+    /// Every coordinate, row by row.
+    pub fn coords(&self) -> impl Iterator<Item = Coord> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> {
+        self.coords().zip(self.cells.iter())
+    }
+
+    pub fn position(&self, predicate: impl FnMut(&T) -> bool) -> Option<Coord> {
+        self.cells.iter().position(predicate).map(|i| (i % self.width, i / self.width))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn filled(width: usize, height: usize, value: T) -> Self {
+        Self::new(width, height, vec![value; width * height])
+    }
+
+    pub fn transpose(&self) -> Self {
+        self.remap(self.height, self.width, |(x, y)| (y, x))
+    }
+
+    pub fn rotate_clockwise(&self) -> Self {
+        let height = self.height;
+        self.remap(self.height, self.width, |(x, y)| (y, height - 1 - x))
+    }
+
+    pub fn rotate_counterclockwise(&self) -> Self {
+        let width = self.width;
+        self.remap(self.height, self.width, |(x, y)| (width - 1 - y, x))
+    }
+
+    // new grid of the given size, `source` gives the coordinates of each new cell in `self`
+    fn remap(&self, width: usize, height: usize, source: impl Fn(Coord) -> Coord) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|coord| self[source(coord)].clone())
+            .collect();
+        Self::new(width, height, cells)
+    }
+}
+
+impl<T> Index<Coord> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): Coord) -> &T {
+        assert!(x < self.width && y < self.height, "({x}, {y}) out of a {}x{} grid", self.width, self.height);
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<Coord> for Grid<T> {
+    fn index_mut(&mut self, (x, y): Coord) -> &mut T {
+        assert!(x < self.width && y < self.height, "({x}, {y}) out of a {}x{} grid", self.width, self.height);
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+// one line per row, without a trailing new line
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 4 wide, 3 high
+    const INPUT: &str = "abcd\nefgh\nijkl";
+
+    fn grid() -> Grid<char> {
+        Grid::parse(INPUT, |c| c)
+    }
+
+    #[test]
+    fn rotations() {
+        let grid = grid();
+        let clockwise = grid.rotate_clockwise();
+        assert_eq!((clockwise.width(), clockwise.height()), (3, 4));
+        assert_eq!(clockwise.to_string(), "iea\njfb\nkgc\nlhd");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "dhl\ncgk\nbfj\naei");
+
+        assert_eq!(clockwise.rotate_counterclockwise(), grid);
+        assert_eq!(grid.rotate_counterclockwise().rotate_clockwise(), grid);
+        let mut rotated = grid.clone();
+        for _ in 0..4 {
+            rotated = rotated.rotate_clockwise();
+        }
+        assert_eq!(rotated, grid);
+        assert_eq!(grid.transpose().transpose(), grid);
+    }
+
+    #[test]
+    fn diagonals() {
+        let grid = grid();
+        let diagonals: Vec<String> = grid.diagonals().map(|d| d.collect()).collect();
+        assert_eq!(diagonals.len(), grid.width() + grid.height() - 1);
+        assert_eq!(diagonals, ["i", "ej", "afk", "bgl", "ch", "d"]);
+
+        let anti_diagonals: Vec<String> = grid.anti_diagonals().map(|d| d.collect()).collect();
+        assert_eq!(anti_diagonals.len(), grid.width() + grid.height() - 1);
+        assert_eq!(anti_diagonals, ["a", "be", "cfi", "dgj", "hk", "l"]);
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = grid();
+        // corners, edges and the middle
+        assert_eq!(grid.neighbours8((0, 0)).count(), 3);
+        assert_eq!(grid.neighbours8((3, 2)).count(), 3);
+        assert_eq!(grid.neighbours8((1, 0)).count(), 5);
+        assert_eq!(grid.neighbours8((0, 1)).count(), 5);
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+        assert_eq!(grid.neighbours4((0, 0)).count(), 2);
+        assert_eq!(grid.neighbours4((3, 1)).count(), 3);
+        assert_eq!(grid.neighbours4((1, 1)).count(), 4);
+
+        let around: Vec<char> = grid.neighbours8((3, 0)).map(|c| grid[c]).collect();
+        assert_eq!(around, ['h', 'g', 'c']);
+    }
+}
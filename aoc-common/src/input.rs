@@ -0,0 +1,80 @@
+//! Loading of the puzzle input files.
+//!
+//! Each day keeps its `input.txt` either next to the crate's `Cargo.toml`
+//! (2021, 2024) or in the day folder shared by the `pb1`/`pb2` crates (2022,
+//! 2023). Files are looked up relative to the crate manifest rather than the
+//! current directory, so a binary can be run from anywhere.
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+pub const INPUT_FILE_NAME: &str = "input.txt";
+
+pub enum InputError {
+    NotFound { file_name: String, searched: Vec<PathBuf> },
+    Io { path: PathBuf, source: io::Error },
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::NotFound { file_name, searched } => {
+                write!(f, "file not found: {file_name} (searched in")?;
+                for dir in searched {
+                    write!(f, " {}", dir.display())?;
+                }
+                write!(f, ")")
+            }
+            Self::Io { path, source } => write!(f, "could not read {}: {source}", path.display()),
+        }
+    }
+}
+
+// `main` prints the `Debug` representation of the error it returns
+impl fmt::Debug for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::NotFound { .. } => None,
+            Self::Io { source, .. } => Some(source),
+        }
+    }
+}
+
+// returns: path of `file_name` in `dir` or, failing that, in the parent of `dir`
+pub fn locate(dir: impl AsRef<Path>, file_name: &str) -> Result<PathBuf, InputError> {
+    let dir = dir.as_ref();
+    let searched: Vec<PathBuf> = [Some(dir), dir.parent()].into_iter().flatten().map(Path::to_path_buf).collect();
+
+    searched
+        .iter()
+        .map(|d| d.join(file_name))
+        .find(|path| path.is_file())
+        .ok_or_else(|| InputError::NotFound { file_name: file_name.to_string(), searched })
+}
+
+pub fn read_input(dir: impl AsRef<Path>, file_name: &str) -> Result<String, InputError> {
+    let path = locate(dir, file_name)?;
+    fs::read_to_string(&path).map_err(|source| InputError::Io { path, source })
+}
+
+/// Reads an input file of the calling crate, see [`locate`] for the lookup rules.
+///
+/// `load_input!()` reads `input.txt`, `load_input!("input_test.txt")` reads another file.
+#[macro_export]
+macro_rules! load_input {
+    () => {
+        $crate::load_input!($crate::INPUT_FILE_NAME)
+    };
+    ($file_name:expr) => {
+        $crate::read_input(::core::env!("CARGO_MANIFEST_DIR"), $file_name)
+    };
+}
+
@@ -0,0 +1,339 @@
+//! Sets of integers made of half-open intervals `start..end`, in one dimension
+//! (`IntervalSet`) or as boxes in N dimensions (`Box<N>`).
+//!
+//! `Box` shadows the standard one: use it as `interval::Box`.
+
+use std::fmt;
+use std::ops::Range;
+
+/// Sorted, disjoint and non-adjacent intervals.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct IntervalSet {
+    ranges: Vec<Range<i64>>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn ranges(&self) -> &[Range<i64>] {
+        &self.ranges
+    }
+
+    /// Number of integers in the set.
+    pub fn len(&self) -> i64 {
+        self.ranges.iter().map(|r| r.end - r.start).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    pub fn min(&self) -> Option<i64> {
+        self.ranges.first().map(|r| r.start)
+    }
+
+    pub fn max(&self) -> Option<i64> {
+        self.ranges.last().map(|r| r.end - 1)
+    }
+
+    pub fn contains(&self, x: i64) -> bool {
+        // the first range ending after x is the only one that can contain it
+        let i = self.ranges.partition_point(|r| r.end <= x);
+        self.ranges.get(i).is_some_and(|r| r.start <= x)
+    }
+
+    /// Adds every integer of `range`, merging the ranges it overlaps or touches.
+    pub fn insert(&mut self, range: Range<i64>) {
+        if range.is_empty() {
+            return;
+        }
+        let first = self.ranges.partition_point(|r| r.end < range.start);
+        let last = self.ranges.partition_point(|r| r.start <= range.end);
+        let mut merged = range;
+        if first < last {
+            merged.start = merged.start.min(self.ranges[first].start);
+            merged.end = merged.end.max(self.ranges[last - 1].end);
+        }
+        self.ranges.splice(first..last, [merged]);
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut res = self.clone();
+        for r in other.ranges.iter() {
+            res.insert(r.clone());
+        }
+        res
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (a, b) = (&self.ranges[i], &other.ranges[j]);
+            let r = a.start.max(b.start)..a.end.min(b.end);
+            if !r.is_empty() {
+                ranges.push(r);
+            }
+            // the range ending first cannot overlap anything else
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        Self { ranges }
+    }
+
+    /// The integers of `self` that are not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let mut j = 0;
+        for a in self.ranges.iter() {
+            let mut start = a.start;
+            while j < other.ranges.len() && other.ranges[j].end <= start {
+                j += 1;
+            }
+            let mut k = j;
+            while k < other.ranges.len() && other.ranges[k].start < a.end {
+                let b = &other.ranges[k];
+                if start < b.start {
+                    ranges.push(start..b.start);
+                }
+                start = start.max(b.end);
+                k += 1;
+            }
+            if start < a.end {
+                ranges.push(start..a.end);
+            }
+        }
+        Self { ranges }
+    }
+
+    /// Splits the set into the integers below `at` and the ones from `at`.
+    pub fn split(&self, at: i64) -> (Self, Self) {
+        let below = Self::from(i64::MIN..at);
+        (self.intersection(&below), self.difference(&below))
+    }
+
+    /// Every integer of the set moved by `delta`.
+    pub fn shifted(&self, delta: i64) -> Self {
+        let ranges = self.ranges.iter().map(|r| r.start + delta..r.end + delta).collect();
+        Self { ranges }
+    }
+}
+
+impl From<Range<i64>> for IntervalSet {
+    fn from(range: Range<i64>) -> Self {
+        let mut res = Self::new();
+        res.insert(range);
+        res
+    }
+}
+
+impl FromIterator<Range<i64>> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Range<i64>>>(iter: I) -> Self {
+        let mut res = Self::new();
+        for r in iter {
+            res.insert(r);
+        }
+        res
+    }
+}
+
+impl fmt::Display for IntervalSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let ranges: Vec<String> = self.ranges.iter().map(|r| format!("{}..{}", r.start, r.end)).collect();
+        write!(f, "{{{}}}", ranges.join(", "))
+    }
+}
+
+/// The integer points with their coordinate along each axis `i` in `ranges[i]`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Box<const N: usize> {
+    pub ranges: [Range<i64>; N],
+}
+
+impl<const N: usize> Box<N> {
+    pub fn new(ranges: [Range<i64>; N]) -> Self {
+        Self { ranges }
+    }
+
+    /// Number of integer points in the box.
+    pub fn volume(&self) -> i64 {
+        self.ranges.iter().map(|r| (r.end - r.start).max(0)).product()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.iter().any(|r| r.is_empty())
+    }
+
+    pub fn contains(&self, point: &[i64; N]) -> bool {
+        self.ranges.iter().zip(point).all(|(r, x)| r.contains(x))
+    }
+
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.ranges.iter().zip(other.ranges.iter()).all(|(a, b)| a.start.max(b.start) < a.end.min(b.end))
+    }
+
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let res = Self::new(std::array::from_fn(|i| {
+            self.ranges[i].start.max(other.ranges[i].start)..self.ranges[i].end.min(other.ranges[i].end)
+        }));
+        (!res.is_empty()).then_some(res)
+    }
+
+    /// Disjoint boxes covering the points of both boxes.
+    pub fn union(&self, other: &Self) -> Vec<Self> {
+        let mut res = vec![self.clone()];
+        res.extend(other.difference(self));
+        res
+    }
+
+    /// Disjoint boxes covering the points of `self` that are not in `other`, at most 2 by axis.
+    pub fn difference(&self, other: &Self) -> Vec<Self> {
+        if !self.intersects(other) {
+            return vec![self.clone()];
+        }
+        // cut the slabs before and after other along each axis in turn, what remains of
+        // self shrinks to the intersection
+        let mut res = Vec::new();
+        let mut remaining = self.clone();
+        for axis in 0..N {
+            let (before, rest) = remaining.split(axis, other.ranges[axis].start);
+            let (rest, after) = rest.unwrap().split(axis, other.ranges[axis].end);
+            res.extend(before);
+            res.extend(after);
+            remaining = rest.unwrap();
+        }
+        res
+    }
+
+    /// Splits the box along `axis` into the points with a coordinate below `at` and the ones
+    /// from `at`, None for an empty side.
+    pub fn split(&self, axis: usize, at: i64) -> (Option<Self>, Option<Self>) {
+        let range = &self.ranges[axis];
+        let mut below = self.clone();
+        below.ranges[axis] = range.start..range.end.min(at);
+        let mut above = self.clone();
+        above.ranges[axis] = range.start.max(at)..range.end;
+        ((!below.is_empty()).then_some(below), (!above.is_empty()).then_some(above))
+    }
+
+    /// The box moved by `delta` along `axis`.
+    pub fn shifted(&self, axis: usize, delta: i64) -> Self {
+        let mut res = self.clone();
+        res.ranges[axis] = res.ranges[axis].start + delta..res.ranges[axis].end + delta;
+        res
+    }
+}
+
+impl<const N: usize> fmt::Display for Box<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let ranges: Vec<String> = self.ranges.iter().map(|r| format!("{}..{}", r.start, r.end)).collect();
+        write!(f, "{}", ranges.join(" x "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[Range<i64>]) -> IntervalSet {
+        ranges.iter().cloned().collect()
+    }
+
+    // pairs of boxes overlapping in every way: partly, one in the other, sharing a bound,
+    // touching without overlap, apart
+    fn box_pairs() -> Vec<(Box<3>, Box<3>)> {
+        let b = |x: Range<i64>, y: Range<i64>, z: Range<i64>| Box::new([x, y, z]);
+        vec![
+            (b(0..4, 0..4, 0..4), b(2..6, 1..3, -1..2)),
+            (b(0..4, 0..4, 0..4), b(1..3, 1..3, 1..3)),
+            (b(1..3, 1..3, 1..3), b(0..4, 0..4, 0..4)),
+            (b(0..4, 0..4, 0..4), b(0..4, 0..2, 0..4)),
+            (b(0..4, 0..4, 0..4), b(0..4, 0..4, 0..4)),
+            (b(0..4, 0..4, 0..4), b(4..6, 0..4, 0..4)),
+            (b(0..4, 0..4, 0..4), b(3..6, 3..6, 3..6)),
+            (b(0..4, 0..4, 0..4), b(10..12, 0..4, 0..4)),
+        ]
+    }
+
+    fn assert_disjoint(boxes: &[Box<3>]) {
+        for (i, a) in boxes.iter().enumerate() {
+            assert!(!a.is_empty(), "{a}");
+            for b in boxes[i + 1..].iter() {
+                assert!(!a.intersects(b), "{a} and {b} overlap");
+            }
+        }
+    }
+
+    #[test]
+    fn box_difference() {
+        for (a, b) in box_pairs() {
+            let pieces = a.difference(&b);
+            assert_disjoint(&pieces);
+            for piece in pieces.iter() {
+                assert_eq!(a.intersection(piece).as_ref(), Some(piece), "{piece} out of {a}");
+                assert!(!piece.intersects(&b), "{piece} in {b}");
+            }
+            let common = a.intersection(&b).map_or(0, |c| c.volume());
+            assert_eq!(pieces.iter().map(|p| p.volume()).sum::<i64>(), a.volume() - common, "{a} - {b}");
+        }
+    }
+
+    #[test]
+    fn box_union() {
+        for (a, b) in box_pairs() {
+            let pieces = a.union(&b);
+            assert_disjoint(&pieces);
+            let common = a.intersection(&b).map_or(0, |c| c.volume());
+            assert_eq!(pieces.iter().map(|p| p.volume()).sum::<i64>(), a.volume() + b.volume() - common, "{a} + {b}");
+        }
+    }
+
+    #[test]
+    fn box_split() {
+        let a = Box::new([0..4, 2..5]);
+        assert_eq!(a.split(1, 3), (Some(Box::new([0..4, 2..3])), Some(Box::new([0..4, 3..5]))));
+        // at a bound
+        assert_eq!(a.split(1, 2), (None, Some(a.clone())));
+        assert_eq!(a.split(1, 5), (Some(a.clone()), None));
+        // outside
+        assert_eq!(a.split(0, -3), (None, Some(a.clone())));
+        assert_eq!(a.split(0, 10), (Some(a.clone()), None));
+    }
+
+    #[test]
+    fn set_difference() {
+        let a = set(&[0..5, 8..12, 15..20]);
+        assert_eq!(a.difference(&set(&[3..9, 11..16])), set(&[0..3, 9..11, 16..20]));
+        assert_eq!(a.difference(&set(&[5..8, 12..15])), a);
+        assert_eq!(a.difference(&IntervalSet::from(0..20)), IntervalSet::new());
+        assert_eq!(a.difference(&set(&[-5..0, 20..30])), a);
+
+        // every integer, against the sets themselves
+        let b = set(&[-2..1, 4..9, 10..11, 19..25]);
+        let diff = a.difference(&b);
+        for x in -5..30 {
+            assert_eq!(diff.contains(x), a.contains(x) && !b.contains(x), "{x}");
+        }
+        assert_eq!(diff.len(), a.len() - a.intersection(&b).len());
+    }
+
+    #[test]
+    fn set_split() {
+        let a = set(&[0..5, 8..12]);
+        assert_eq!(a.split(3), (IntervalSet::from(0..3), set(&[3..5, 8..12])));
+        // at a bound
+        assert_eq!(a.split(0), (IntervalSet::new(), a.clone()));
+        assert_eq!(a.split(5), (IntervalSet::from(0..5), IntervalSet::from(8..12)));
+        assert_eq!(a.split(8), (IntervalSet::from(0..5), IntervalSet::from(8..12)));
+        assert_eq!(a.split(12), (a.clone(), IntervalSet::new()));
+        // outside
+        assert_eq!(a.split(-10), (IntervalSet::new(), a.clone()));
+        assert_eq!(a.split(100), (a.clone(), IntervalSet::new()));
+        assert_eq!(a.split(i64::MIN), (IntervalSet::new(), a.clone()));
+    }
+}
@@ -0,0 +1,40 @@
+//! Code shared by every Advent of Code crate of the repository.
+
+pub mod answer;
+pub mod cycle;
+pub mod geometry;
+pub mod graph;
+pub mod grid;
+pub mod input;
+pub mod interval;
+pub mod polygon;
+pub mod search;
+pub mod solution;
+
+pub use answer::Answer;
+pub use geometry::{Direction, Point};
+pub use grid::Grid;
+pub use input::{read_input, InputError, INPUT_FILE_NAME};
+pub use solution::Solution;
+
+/// Defines the `main` of a puzzle binary: it reads the input file of the crate
+/// (`input.txt` unless another name is given) and prints the answer of `$solver`.
+///
+/// ```ignore
+/// aoc_common::main!(y2023_day17::part2);
+/// aoc_common::main!(y2023_day12_pb2_v2::part2, "input_test.txt");
+/// aoc_common::main!(aoc_common::solution::part1::<y2024_day07::Day07>);
+/// ```
+#[macro_export]
+macro_rules! main {
+    ($solver:path) => {
+        $crate::main!($solver, $crate::INPUT_FILE_NAME);
+    };
+    ($solver:path, $file_name:expr) => {
+        fn main() -> Result<(), $crate::InputError> {
+            let input = $crate::load_input!($file_name)?;
+            println!("Result: {}", $solver(&input));
+            Ok(())
+        }
+    };
+}
@@ -0,0 +1,185 @@
+//! Simple polygons with their vertices on the integer lattice, like a trench dug in a grid
+//! or a loop of pipes.
+//!
+//! The area comes from the shoelace formula, the number of lattice points inside from Pick's
+//! theorem: `area = interior + boundary / 2 - 1`. Everything is exact, in integers.
+
+use crate::geometry::{Direction, Point};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Polygon {
+    vertices: Vec<Point>,
+}
+
+impl Polygon {
+    /// The polygon going through `vertices` in order, and back from the last one to the first.
+    pub fn new(vertices: Vec<Point>) -> Self {
+        Self { vertices }
+    }
+
+    /// The rectilinear polygon followed from `start` by moving `length` tiles in each
+    /// direction in turn, like the instructions `R 6`, `D 5`...
+    ///
+    /// Panics if the steps do not come back to `start`.
+    pub fn from_steps(start: Point, steps: impl IntoIterator<Item = (Direction, usize)>) -> Self {
+        let mut vertices = vec![start];
+        for (direction, length) in steps {
+            let last = *vertices.last().unwrap();
+            vertices.push(last.step_n(direction, length as isize));
+        }
+        assert_eq!(vertices.pop(), Some(start), "the steps should come back to the start");
+        Self::new(vertices)
+    }
+
+    pub fn vertices(&self) -> &[Point] {
+        &self.vertices
+    }
+
+    fn edges(&self) -> impl Iterator<Item = (Point, Point)> + '_ {
+        let next = self.vertices.iter().cycle().skip(1);
+        self.vertices.iter().copied().zip(next.copied())
+    }
+
+    /// Twice the area, so that it is an integer: the shoelace formula.
+    pub fn double_area(&self) -> usize {
+        let sum: i128 = self.edges().map(|(a, b)| a.x as i128 * b.y as i128 - b.x as i128 * a.y as i128).sum();
+        sum.unsigned_abs() as usize
+    }
+
+    /// Number of lattice points on the edges.
+    pub fn boundary_points(&self) -> usize {
+        self.edges().map(|(a, b)| gcd((b.x - a.x).unsigned_abs(), (b.y - a.y).unsigned_abs())).sum()
+    }
+
+    /// Number of lattice points strictly inside, from Pick's theorem.
+    ///
+    /// Panics if the polygon is flat, with edges going back over each other: the theorem
+    /// only holds for simple polygons.
+    pub fn interior_points(&self) -> usize {
+        let double_interior = (self.double_area() + 2)
+            .checked_sub(self.boundary_points())
+            .expect("Pick's theorem needs a simple polygon, not a flat one");
+        double_interior / 2
+    }
+
+    /// Number of lattice points inside or on the edges: the tiles covered by the polygon when
+    /// its vertices are the centers of tiles.
+    pub fn lattice_points(&self) -> usize {
+        self.interior_points() + self.boundary_points()
+    }
+
+    pub fn on_boundary(&self, p: Point) -> bool {
+        self.edges().any(|(a, b)| {
+            let (u, v) = (b - a, p - a);
+            u.x * v.y == u.y * v.x
+                && p.x >= a.x.min(b.x) && p.x <= a.x.max(b.x)
+                && p.y >= a.y.min(b.y) && p.y <= a.y.max(b.y)
+        })
+    }
+
+    /// If `p` is inside the polygon or on its edges.
+    ///
+    /// A ray going right from `p` crosses the edges an odd number of times when `p` is inside.
+    /// An edge only counts when it has one end strictly below the ray and the other one on it
+    /// or above, so that a vertex on the ray is counted once, or twice if the polygon only
+    /// touches the ray there.
+    pub fn contains(&self, p: Point) -> bool {
+        if self.on_boundary(p) {
+            return true;
+        }
+        let crossings = self.edges()
+            .filter(|(a, b)| (a.y > p.y) != (b.y > p.y))
+            .filter(|(a, b)| {
+                // the edge crosses the ray right of p
+                let side = (b.x - a.x) * (p.y - a.y) - (b.y - a.y) * (p.x - a.x);
+                (side > 0) == (b.y > a.y)
+            })
+            .count();
+        crossings % 2 == 1
+    }
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn polygon(vertices: &[(isize, isize)]) -> Polygon {
+        Polygon::new(vertices.iter().map(|&(x, y)| Point::new(x, y)).collect())
+    }
+
+    // a U open at the bottom: the notch between x = 2 and x = 4 goes up to y = 2
+    fn u_shape() -> Polygon {
+        polygon(&[(0, 0), (6, 0), (6, 6), (4, 6), (4, 2), (2, 2), (2, 6), (0, 6)])
+    }
+
+    #[test]
+    fn rectangle_from_steps() {
+        let steps = [(Direction::East, 3), (Direction::South, 2), (Direction::West, 3), (Direction::North, 2)];
+        let rectangle = Polygon::from_steps(Point::new(1, 1), steps);
+        assert_eq!(rectangle.double_area(), 12);
+        assert_eq!(rectangle.boundary_points(), 10);
+        assert_eq!(rectangle.interior_points(), 2);
+        assert_eq!(rectangle.lattice_points(), 12);
+    }
+
+    #[test]
+    fn concave_lattice_points() {
+        let u = u_shape();
+        assert_eq!(u.double_area(), 2 * (36 - 8));
+        assert_eq!(u.boundary_points(), 32);
+        // 7 rows of 7 points, minus the ones strictly inside the notch: x = 3, from y = 3 to 6
+        assert_eq!(u.lattice_points(), 49 - 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn flat_polygon_has_no_interior() {
+        polygon(&[(0, 0), (3, 0)]).interior_points();
+    }
+
+    #[test]
+    fn inside_and_outside_a_concave_polygon() {
+        let u = u_shape();
+        assert!(u.contains(Point::new(1, 4)));
+        assert!(u.contains(Point::new(5, 1)));
+        assert!(!u.contains(Point::new(3, 4)));
+        assert!(!u.contains(Point::new(7, 3)));
+        assert!(!u.contains(Point::new(-1, 1)));
+    }
+
+    #[test]
+    fn on_an_edge() {
+        let u = u_shape();
+        for p in [Point::new(3, 0), Point::new(4, 4), Point::new(3, 2), Point::new(6, 6)] {
+            assert!(u.on_boundary(p), "{p:?}");
+            assert!(u.contains(p), "{p:?}");
+        }
+        assert!(!u.on_boundary(Point::new(3, 3)));
+        assert!(!u.on_boundary(Point::new(1, 1)));
+
+        let triangle = polygon(&[(0, 0), (4, 0), (0, 4)]);
+        assert!(triangle.on_boundary(Point::new(2, 2)));
+        assert!(!triangle.on_boundary(Point::new(3, 2)));
+        assert!(!triangle.contains(Point::new(3, 2)));
+    }
+
+    #[test]
+    fn ray_through_a_vertex() {
+        let diamond = polygon(&[(0, 2), (2, 0), (4, 2), (2, 4)]);
+        // the ray only touches the top vertex
+        assert!(!diamond.contains(Point::new(-1, 0)));
+        // the ray goes through both side vertices
+        assert!(!diamond.contains(Point::new(-1, 2)));
+        // the ray leaves through the right vertex
+        assert!(diamond.contains(Point::new(1, 2)));
+
+        // the ray runs along the bottom of the notch of the U, through 2 vertices
+        let u = u_shape();
+        assert!(u.contains(Point::new(1, 2)));
+        assert!(!u.contains(Point::new(-1, 2)));
+    }
+}
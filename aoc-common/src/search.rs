@@ -0,0 +1,218 @@
+//! Shortest paths over any state space: Dijkstra, A* and path reconstruction.
+//!
+//! States are discovered through a successor function returning `(next_state, cost)`
+//! pairs, so the graph never has to be built. Costs are non-negative integers.
+
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap};
+use std::hash::Hash;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Path<S> {
+    pub cost: usize,
+    /// From the start to the goal, both included.
+    pub states: Vec<S>,
+}
+
+/// Cheapest path from one of `starts` to a state matching `is_goal`.
+pub fn dijkstra<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl Fn(&S) -> bool,
+) -> Option<Path<S>>
+where
+    S: Clone + Hash + Eq,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    astar(starts, successors, |_| 0, is_goal)
+}
+
+/// Same as `dijkstra`, guided by `heuristic`. The path is the cheapest one as long as the
+/// heuristic never overestimates the remaining cost to a goal.
+pub fn astar<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    heuristic: impl FnMut(&S) -> usize,
+    is_goal: impl Fn(&S) -> bool,
+) -> Option<Path<S>>
+where
+    S: Clone + Hash + Eq,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    let [path] = search(starts, successors, heuristic, &[&is_goal]).try_into().ok()?;
+    path
+}
+
+/// Cheapest path to each of `goals`, in a single exploration: the search stops once every
+/// goal is reached or there is nothing left to explore.
+pub fn dijkstra_multi<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    goals: &[&dyn Fn(&S) -> bool],
+) -> Vec<Option<Path<S>>>
+where
+    S: Clone + Hash + Eq,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    search(starts, successors, |_| 0, goals)
+}
+
+struct Node<S> {
+    state: S,
+    cost: usize,
+    parent: Option<usize>,
+}
+
+fn search<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> usize,
+    goals: &[&dyn Fn(&S) -> bool],
+) -> Vec<Option<Path<S>>>
+where
+    S: Clone + Hash + Eq,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    let mut nodes: Vec<Node<S>> = Vec::new();
+    let mut index: HashMap<S, usize> = HashMap::new();
+    // (estimated total cost, cost so far, node), the smallest first
+    let mut queue = BinaryHeap::new();
+
+    for state in starts {
+        if let Entry::Vacant(e) = index.entry(state.clone()) {
+            e.insert(nodes.len());
+            queue.push(Reverse((heuristic(&state), 0, nodes.len())));
+            nodes.push(Node { state, cost: 0, parent: None });
+        }
+    }
+
+    let mut found = vec![None; goals.len()];
+    let mut left = goals.len();
+
+    while let Some(Reverse((_, cost, i))) = queue.pop() {
+        if cost > nodes[i].cost {
+            continue; // a cheaper way to this state was found after this one was queued
+        }
+        for (goal, found) in goals.iter().zip(found.iter_mut()) {
+            if found.is_none() && goal(&nodes[i].state) {
+                *found = Some(i);
+                left -= 1;
+            }
+        }
+        if left == 0 {
+            break;
+        }
+
+        let state = nodes[i].state.clone();
+        for (next, step_cost) in successors(&state) {
+            let next_cost = cost + step_cost;
+            let j = match index.entry(next) {
+                Entry::Occupied(e) => {
+                    let j = *e.get();
+                    if nodes[j].cost <= next_cost {
+                        continue;
+                    }
+                    nodes[j].cost = next_cost;
+                    nodes[j].parent = Some(i);
+                    j
+                }
+                Entry::Vacant(e) => {
+                    let j = nodes.len();
+                    nodes.push(Node { state: e.key().clone(), cost: next_cost, parent: Some(i) });
+                    e.insert(j);
+                    j
+                }
+            };
+            queue.push(Reverse((next_cost + heuristic(&nodes[j].state), next_cost, j)));
+        }
+    }
+
+    found.into_iter().map(|i| i.map(|i| path_to(&nodes, i))).collect()
+}
+
+fn path_to<S: Clone>(nodes: &[Node<S>], goal: usize) -> Path<S> {
+    let mut states = Vec::new();
+    let mut current = Some(goal);
+    while let Some(i) = current {
+        states.push(nodes[i].state.clone());
+        current = nodes[i].parent;
+    }
+    states.reverse();
+    Path { cost: nodes[goal].cost, states }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::{Coord, Grid};
+
+    // the cost to enter each cell, 0 for a wall
+    const MAP: &str = "\
+1163751
+1381373
+2136511
+3694931
+7463417
+1319128
+1359912";
+
+    fn costs() -> Grid<usize> {
+        Grid::parse(MAP, |c| c.to_digit(10).unwrap() as usize)
+    }
+
+    fn successors(grid: &Grid<usize>) -> impl FnMut(&Coord) -> Vec<(Coord, usize)> + '_ {
+        |&c| grid.neighbours4(c).filter(|n| grid[*n] > 0).map(|n| (n, grid[n])).collect()
+    }
+
+    fn manhattan((x1, y1): Coord, (x2, y2): Coord) -> usize {
+        x1.abs_diff(x2) + y1.abs_diff(y2)
+    }
+
+    #[test]
+    fn astar_with_heuristic() {
+        let grid = costs();
+        let goal = (grid.width() - 1, grid.height() - 1);
+        let reference = dijkstra([(0, 0)], successors(&grid), |c| *c == goal).unwrap();
+        // every cell costs at least 1, the heuristic never overestimates
+        let path = astar([(0, 0)], successors(&grid), |c| manhattan(*c, goal), |c| *c == goal).unwrap();
+        assert_eq!(path.cost, reference.cost);
+        assert_eq!(path.states.first(), Some(&(0, 0)));
+        assert_eq!(path.states.last(), Some(&goal));
+        let cost: usize = path.states[1..].iter().map(|c| grid[*c]).sum();
+        assert_eq!(cost, path.cost);
+    }
+
+    #[test]
+    fn no_path() {
+        let mut grid = costs();
+        grid[(0, 1)] = 0;
+        grid[(1, 0)] = 0;
+        assert_eq!(dijkstra([(0, 0)], successors(&grid), |c| *c == (6, 6)), None);
+    }
+
+    #[test]
+    fn multi_starts_and_goals() {
+        let grid = costs();
+        let starts = [(0, 0), (6, 6), (6, 0)];
+        let near_top_right: &dyn Fn(&Coord) -> bool = &|c| *c == (5, 1);
+        let near_bottom_right: &dyn Fn(&Coord) -> bool = &|c| *c == (6, 5);
+        let unreachable: &dyn Fn(&Coord) -> bool = &|c| c.0 > 10;
+        let paths = dijkstra_multi(starts, successors(&grid), &[near_top_right, near_bottom_right, unreachable]);
+
+        let [Some(top), Some(bottom), None] = &paths[..] else { panic!("{paths:?}") };
+        // each goal is reached from the nearest start
+        assert_eq!(top.states.first(), Some(&(6, 0)));
+        assert_eq!(top.cost, 3 + 7);
+        assert_eq!(bottom.states.first(), Some(&(6, 6)));
+        assert_eq!(bottom.cost, 8);
+        for (path, goal) in [(top, (5, 1)), (bottom, (6, 5))] {
+            let from_each: Vec<usize> = starts
+                .iter()
+                .filter_map(|s| dijkstra([*s], successors(&grid), |c| *c == goal))
+                .map(|p| p.cost)
+                .collect();
+            assert_eq!(Some(path.cost), from_each.into_iter().min());
+        }
+    }
+}
@@ -0,0 +1,20 @@
+//! Puzzles whose two parts share the same parsed input.
+
+use crate::Answer;
+
+pub trait Solution {
+    type Input;
+
+    fn parse(input: &str) -> Self::Input;
+    fn part1(input: &Self::Input) -> Answer;
+    fn part2(input: &Self::Input) -> Answer;
+}
+
+// plain `fn(&str) -> Answer` so that a `Solution` can be given to `main!` and to the runner
+pub fn part1<S: Solution>(input: &str) -> Answer {
+    S::part1(&S::parse(input))
+}
+
+pub fn part2<S: Solution>(input: &str) -> Answer {
+    S::part2(&S::parse(input))
+}
@@ -0,0 +1,89 @@
+//! Timing of several variants of the same puzzle, for `aoc --bench`.
+
+use std::time::{Duration, Instant};
+
+use aoc_common::Answer;
+
+use crate::Solver;
+
+pub struct Stats {
+    pub mean: Duration,
+    pub median: Duration,
+    pub min: Duration,
+    pub max: Duration,
+    pub std_dev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "no sample to compute statistics on");
+        samples.sort();
+        let n = samples.len();
+        let mean = samples.iter().sum::<Duration>() / n as u32;
+        let median = if n % 2 == 1 {
+            samples[n / 2]
+        } else {
+            (samples[n / 2 - 1] + samples[n / 2]) / 2
+        };
+        let variance = samples.iter()
+            .map(|s| (s.as_secs_f64() - mean.as_secs_f64()).powi(2))
+            .sum::<f64>() / n as f64;
+        Self {
+            mean,
+            median,
+            min: samples[0],
+            max: samples[n - 1],
+            std_dev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+pub struct Measure<'a> {
+    pub solver: &'a Solver,
+    pub answer: Answer,
+    pub stats: Stats,
+}
+
+// one untimed run first, to warm up caches and allocator
+pub fn measure<'a>(solver: &'a Solver, input: &str, samples: usize) -> Measure<'a> {
+    let answer = (solver.run)(input);
+    let samples = (0..samples)
+        .map(|_| {
+            let now = Instant::now();
+            (solver.run)(input);
+            now.elapsed()
+        })
+        .collect();
+    Measure { solver, answer, stats: Stats::from_samples(samples) }
+}
+
+// measures of the same year, day and part, printed with their time relative to the fastest mean
+pub fn print_table(measures: &[Measure]) {
+    let Some(first) = measures.first() else { return };
+    let fastest = measures.iter().map(|m| m.stats.mean).min().unwrap();
+    let same_answer = measures.iter().all(|m| m.answer == first.answer);
+
+    println!("{} day {} part {}", first.solver.year, first.solver.day, first.solver.part);
+    println!(
+        "  {:<10} {:>12} {:>12} {:>12} {:>12} {:>12} {:>8}  answer",
+        "variant", "mean", "median", "min", "max", "std dev", "ratio"
+    );
+    for m in measures {
+        let variant = if m.solver.variant.is_empty() { "-" } else { m.solver.variant };
+        let answer = m.answer.to_string().replace('\n', "\\n");
+        println!(
+            "  {:<10} {:>12} {:>12} {:>12} {:>12} {:>12} {:>8.2}  {}",
+            variant,
+            format!("{:.2?}", m.stats.mean),
+            format!("{:.2?}", m.stats.median),
+            format!("{:.2?}", m.stats.min),
+            format!("{:.2?}", m.stats.max),
+            format!("{:.2?}", m.stats.std_dev),
+            m.stats.mean.as_secs_f64() / fastest.as_secs_f64(),
+            answer,
+        );
+    }
+    if !same_answer {
+        println!("  warning: the variants do not agree on the answer");
+    }
+}
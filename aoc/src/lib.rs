@@ -0,0 +1,152 @@
+//! Registry of every solver in the repository, used by the `aoc` runner.
+
+use std::path::{Path, PathBuf};
+
+use aoc_common::{solution, Answer};
+
+pub mod bench;
+pub mod skip;
+
+pub struct Solver {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    /// empty for the reference solution, otherwise the suffix of the crate (`v2`, `threads`...)
+    pub variant: &'static str,
+    /// crate directory, relative to the repository root
+    pub dir: &'static str,
+    pub run: fn(&str) -> Answer,
+}
+
+impl Solver {
+    pub fn dir(&self) -> PathBuf {
+        root().join(self.dir)
+    }
+
+    pub fn name(&self) -> String {
+        if self.variant.is_empty() {
+            format!("{} day {} part {}", self.year, self.day, self.part)
+        } else {
+            format!("{} day {} part {} ({})", self.year, self.day, self.part, self.variant)
+        }
+    }
+}
+
+pub fn root() -> &'static Path {
+    Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/.."))
+}
+
+macro_rules! solver {
+    ($year:expr, $day:expr, $part:expr, $variant:expr, $dir:expr, $run:path) => {
+        Solver { year: $year, day: $day, part: $part, variant: $variant, dir: $dir, run: $run }
+    };
+}
+
+pub static SOLVERS: &[Solver] = &[
+    solver!(2021, 1, 1, "", "2021/day-01", y2021_day01::part1),
+    solver!(2021, 1, 2, "", "2021/day-01", y2021_day01::part2),
+    solver!(2021, 2, 1, "", "2021/day-02", y2021_day02::part1),
+    solver!(2021, 2, 2, "", "2021/day-02", y2021_day02::part2),
+    solver!(2021, 3, 1, "", "2021/day-03", y2021_day03::part1),
+    solver!(2021, 3, 2, "", "2021/day-03", y2021_day03::part2),
+    solver!(2021, 4, 1, "", "2021/day-04", y2021_day04::part1),
+    solver!(2021, 4, 2, "", "2021/day-04", y2021_day04::part2),
+    solver!(2021, 5, 1, "", "2021/day-05", y2021_day05::part1),
+    solver!(2021, 5, 2, "", "2021/day-05", y2021_day05::part2),
+    solver!(2022, 1, 1, "", "2022/Day1", y2022_day01_pb1::part1),
+    solver!(2022, 1, 2, "", "2022/Day1_2", y2022_day01_pb2::part2),
+    solver!(2022, 2, 1, "", "2022/Day2/Pb1", y2022_day02_pb1::part1),
+    solver!(2022, 2, 2, "", "2022/Day2/Pb2", y2022_day02_pb2::part2),
+    solver!(2022, 3, 1, "", "2022/Day3/pb1", y2022_day03_pb1::part1),
+    solver!(2022, 3, 2, "", "2022/Day3/pb2", y2022_day03_pb2::part2),
+    solver!(2022, 4, 1, "", "2022/Day4/pb1", y2022_day04_pb1::part1),
+    solver!(2022, 4, 2, "", "2022/Day4/pb2", y2022_day04_pb2::part2),
+    solver!(2022, 5, 1, "", "2022/Day5/pb1", y2022_day05_pb1::part1),
+    solver!(2022, 5, 2, "", "2022/Day5/pb2", y2022_day05_pb2::part2),
+    solver!(2022, 6, 1, "", "2022/Day6/pb1", y2022_day06_pb1::part1),
+    solver!(2022, 6, 2, "", "2022/Day6/pb2", y2022_day06_pb2::part2),
+    solver!(2022, 7, 1, "", "2022/Day7/pb1", y2022_day07_pb1::part1),
+    solver!(2022, 7, 2, "", "2022/Day7/pb2", y2022_day07_pb2::part2),
+    solver!(2022, 8, 1, "", "2022/Day8/pb1", y2022_day08_pb1::part1),
+    solver!(2022, 9, 1, "", "2022/Day9/pb1", y2022_day09_pb1::part1),
+    solver!(2022, 9, 2, "", "2022/Day9/pb2", y2022_day09_pb2::part2),
+    solver!(2022, 10, 1, "", "2022/Day10/pb1", y2022_day10_pb1::part1),
+    solver!(2022, 10, 2, "", "2022/Day10/pb2", y2022_day10_pb2::part2),
+    solver!(2023, 3, 1, "", "2023/Day3/pb1", y2023_day03_pb1::part1),
+    solver!(2023, 3, 2, "", "2023/Day3/pb2", y2023_day03_pb2::part2),
+    solver!(2023, 4, 1, "", "2023/Day4/pb1", y2023_day04_pb1::part1),
+    solver!(2023, 4, 2, "", "2023/Day4/pb2", y2023_day04_pb2::part2),
+    solver!(2023, 5, 1, "", "2023/Day5/pb1", y2023_day05_pb1::part1),
+    solver!(2023, 5, 2, "", "2023/Day5/pb2", y2023_day05_pb2::part2),
+    solver!(2023, 5, 2, "v2", "2023/Day5/pb2-v2", y2023_day05_pb2_v2::part2),
+    // the pb1 crate was reworked for part 2 (a single race once the spaces are removed)
+    solver!(2023, 6, 2, "", "2023/Day6/pb1", y2023_day06_pb1::part2),
+    solver!(2023, 7, 1, "", "2023/Day7/pb1", y2023_day07_pb1::part1),
+    solver!(2023, 7, 2, "", "2023/Day7/pb2", y2023_day07_pb2::part2),
+    solver!(2023, 8, 1, "", "2023/Day8/pb1", y2023_day08_pb1::part1),
+    solver!(2023, 8, 2, "", "2023/Day8/pb2", y2023_day08_pb2::part2),
+    solver!(2023, 8, 2, "v2", "2023/Day8/pb2-v2", y2023_day08_pb2_v2::part2),
+    solver!(2023, 8, 2, "v3", "2023/Day8/pb2-v3", y2023_day08_pb2_v3::part2),
+    solver!(2023, 9, 1, "", "2023/Day9/pb1", y2023_day09_pb1::part1),
+    solver!(2023, 9, 2, "", "2023/Day9/pb2", y2023_day09_pb2::part2),
+    solver!(2023, 10, 1, "", "2023/Day10", y2023_day10::part1),
+    solver!(2023, 10, 2, "", "2023/Day10", y2023_day10::part2),
+    solver!(2023, 11, 1, "", "2023/Day11/pb1", y2023_day11_pb1::part1),
+    solver!(2023, 11, 2, "", "2023/Day11/pb2", y2023_day11_pb2::part2),
+    solver!(2023, 12, 1, "", "2023/Day12", y2023_day12::part1),
+    solver!(2023, 12, 2, "", "2023/Day12", y2023_day12::part2),
+    solver!(2023, 12, 2, "threads", "2023/Day12/pb2-threads", y2023_day12_pb2_threads::part2),
+    solver!(2023, 12, 2, "v2", "2023/Day12/pb2-v2", y2023_day12_pb2_v2::part2),
+    solver!(2023, 12, 2, "v3", "2023/Day12/pb2-v3", y2023_day12_pb2_v3::part2),
+    solver!(2023, 13, 1, "", "2023/Day13/pb1", y2023_day13_pb1::part1),
+    solver!(2023, 13, 2, "", "2023/Day13/pb2", y2023_day13_pb2::part2),
+    solver!(2023, 14, 1, "", "2023/Day14", y2023_day14::part1),
+    solver!(2023, 14, 2, "", "2023/Day14", y2023_day14::part2),
+    solver!(2023, 15, 1, "", "2023/Day15/pb1", y2023_day15_pb1::part1),
+    solver!(2023, 15, 2, "", "2023/Day15/pb2", y2023_day15_pb2::part2),
+    solver!(2023, 16, 1, "", "2023/Day16/pb1", y2023_day16_pb1::part1),
+    solver!(2023, 16, 2, "", "2023/Day16/pb2", y2023_day16_pb2::part2),
+    solver!(2023, 16, 2, "threads", "2023/Day16/pb2-threads", y2023_day16_pb2_threads::part2),
+    solver!(2023, 17, 1, "", "2023/Day17", y2023_day17::part1),
+    solver!(2023, 17, 2, "", "2023/Day17", y2023_day17::part2),
+    solver!(2023, 18, 1, "", "2023/Day18", y2023_day18::part1),
+    solver!(2023, 18, 2, "", "2023/Day18", y2023_day18::part2),
+    solver!(2023, 19, 1, "", "2023/Day19", solution::part1::<y2023_day19::Day19>),
+    solver!(2023, 19, 2, "", "2023/Day19", solution::part2::<y2023_day19::Day19>),
+    solver!(2023, 20, 1, "", "2023/Day20", y2023_day20::part1),
+    solver!(2023, 20, 2, "", "2023/Day20", y2023_day20::part2),
+    solver!(2023, 21, 1, "", "2023/Day21", y2023_day21::part1),
+    solver!(2023, 21, 2, "", "2023/Day21", y2023_day21::part2),
+    solver!(2023, 22, 1, "", "2023/Day22", y2023_day22::part1),
+    solver!(2023, 22, 2, "", "2023/Day22", y2023_day22::part2),
+    solver!(2023, 23, 1, "", "2023/Day23/pb1", y2023_day23_pb1::part1),
+    solver!(2023, 23, 2, "", "2023/Day23/pb2", y2023_day23_pb2::part2),
+    solver!(2023, 24, 1, "", "2023/Day24/pb1", y2023_day24_pb1::part1),
+    solver!(2023, 24, 2, "", "2023/Day24/pb2", y2023_day24_pb2::part2),
+    solver!(2023, 25, 1, "", "2023/Day25/pb1", y2023_day25_pb1::part1),
+    solver!(2024, 1, 1, "", "2024/day-01", y2024_day01::part1),
+    solver!(2024, 1, 2, "", "2024/day-01", y2024_day01::part2),
+    solver!(2024, 2, 1, "", "2024/day-02", y2024_day02::part1),
+    solver!(2024, 2, 2, "", "2024/day-02", y2024_day02::part2),
+    solver!(2024, 3, 1, "", "2024/day-03", y2024_day03::part1),
+    solver!(2024, 3, 2, "", "2024/day-03", y2024_day03::part2),
+    solver!(2024, 4, 1, "", "2024/day-04", y2024_day04::part1),
+    solver!(2024, 4, 2, "", "2024/day-04", y2024_day04::part2),
+    solver!(2024, 5, 1, "", "2024/day-05", y2024_day05::part1),
+    solver!(2024, 5, 2, "", "2024/day-05", y2024_day05::part2),
+    solver!(2024, 6, 1, "", "2024/day-06", y2024_day06::part1),
+    solver!(2024, 6, 2, "", "2024/day-06", y2024_day06::part2),
+    solver!(2024, 6, 2, "par", "2024/day-06", y2024_day06::part2_par),
+    solver!(2024, 7, 1, "", "2024/day-07", solution::part1::<y2024_day07::Day07>),
+    solver!(2024, 7, 2, "", "2024/day-07", solution::part2::<y2024_day07::Day07>),
+    solver!(2024, 8, 1, "", "2024/day-08", y2024_day08::part1),
+    solver!(2024, 8, 2, "", "2024/day-08", y2024_day08::part2),
+    solver!(2024, 9, 1, "", "2024/day-09", y2024_day09::part1),
+    solver!(2024, 9, 2, "", "2024/day-09", y2024_day09::part2),
+    solver!(2024, 10, 1, "", "2024/day-10", y2024_day10::part1),
+    solver!(2024, 10, 2, "", "2024/day-10", y2024_day10::part2),
+    solver!(2024, 11, 1, "", "2024/day-11", y2024_day11::part1),
+    solver!(2024, 11, 2, "", "2024/day-11", y2024_day11::part2),
+    solver!(2024, 12, 1, "", "2024/day-12", y2024_day12::part1),
+    solver!(2024, 12, 2, "", "2024/day-12", y2024_day12::part2),
+];
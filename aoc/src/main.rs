@@ -0,0 +1,174 @@
+use std::env;
+use std::fs;
+use std::panic;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::Instant;
+
+use aoc::bench::{self, Measure};
+use aoc::skip::skips;
+use aoc::{Solver, SOLVERS};
+use aoc_common::{read_input, INPUT_FILE_NAME};
+
+const USAGE: &str = "usage: aoc [--year YEAR] [--day DAY] [--part PART] [--variant NAME|all] [--input PATH] [--list] [--bench SAMPLES]";
+
+#[derive(Default)]
+struct Args {
+    year: Option<u16>,
+    day: Option<u8>,
+    part: Option<u8>,
+    variant: Option<String>,
+    input: Option<PathBuf>,
+    list: bool,
+    // number of timed runs of each solver, every variant is compared unless `--variant` is given,
+    // except those skipped on the input in `answers.txt`
+    bench: Option<usize>,
+}
+
+impl Args {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Args, String> {
+        let mut res = Args::default();
+        while let Some(arg) = args.next() {
+            if arg == "--list" {
+                res.list = true;
+                continue;
+            }
+            let value = match arg.as_str() {
+                "--year" | "--day" | "--part" | "--variant" | "--input" | "--bench" => args.next().ok_or(format!("missing value for {arg}"))?,
+                "-h" | "--help" => return Err(USAGE.to_string()),
+                _ => return Err(format!("unknown argument {arg}\n{USAGE}")),
+            };
+            let invalid = |_| format!("invalid value for {arg}: {value}");
+            match arg.as_str() {
+                "--year" => res.year = Some(value.parse().map_err(invalid)?),
+                "--day" => res.day = Some(value.parse().map_err(invalid)?),
+                "--part" => res.part = Some(value.parse().map_err(invalid)?),
+                "--variant" => res.variant = Some(value),
+                "--bench" => res.bench = Some(value.parse().ok().filter(|&n| n > 0).ok_or(format!("invalid value for {arg}: {value}"))?),
+                _ => res.input = Some(PathBuf::from(value)),
+            }
+        }
+        Ok(res)
+    }
+
+    fn matches(&self, solver: &Solver) -> bool {
+        self.year.is_none_or(|y| y == solver.year)
+            && self.day.is_none_or(|d| d == solver.day)
+            && self.part.is_none_or(|p| p == solver.part)
+            && match self.variant.as_deref() {
+                None => self.bench.is_some() || solver.variant.is_empty(),
+                Some("all") => true,
+                Some(v) => v == solver.variant,
+            }
+    }
+
+    // the file name of the input, as written in `answers.txt`
+    fn input_name(&self) -> String {
+        self.input
+            .as_ref()
+            .and_then(|path| path.file_name())
+            .map_or(INPUT_FILE_NAME.to_string(), |name| name.to_string_lossy().into_owned())
+    }
+
+    fn read_input(&self, solver: &Solver) -> Result<String, String> {
+        match &self.input {
+            // a path that does not exist from here is looked up like a file name, next to the solver
+            Some(path) if path.exists() => fs::read_to_string(path).map_err(|e| format!("cannot read {}: {e}", path.display())),
+            Some(path) => read_input(solver.dir(), &path.to_string_lossy()).map_err(|e| e.to_string()),
+            None => read_input(solver.dir(), INPUT_FILE_NAME).map_err(|e| e.to_string()),
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let args = match Args::parse(env::args().skip(1)) {
+        Ok(args) => args,
+        Err(msg) => {
+            eprintln!("{msg}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let solvers: Vec<&Solver> = SOLVERS.iter().filter(|s| args.matches(s)).collect();
+    if solvers.is_empty() {
+        eprintln!("no solver registered for this selection");
+        return ExitCode::FAILURE;
+    }
+
+    if args.list {
+        for solver in solvers {
+            println!("{} ({})", solver.name(), solver.dir);
+        }
+        return ExitCode::SUCCESS;
+    }
+
+    if let Some(samples) = args.bench {
+        return run_bench(&args, &solvers, samples);
+    }
+
+    let mut code = ExitCode::SUCCESS;
+    for solver in solvers {
+        let input = match args.read_input(solver) {
+            Ok(input) => input,
+            Err(msg) => {
+                eprintln!("{}: {msg}", solver.name());
+                code = ExitCode::FAILURE;
+                continue;
+            }
+        };
+        let now = Instant::now();
+        let answer = match panic::catch_unwind(|| (solver.run)(&input)) {
+            Ok(answer) => answer,
+            Err(_) => {
+                eprintln!("{}: panicked", solver.name());
+                code = ExitCode::FAILURE;
+                continue;
+            }
+        };
+        let elapsed = now.elapsed();
+        println!("{}: {answer} [{elapsed:?}]", solver.name());
+    }
+    code
+}
+
+// the tables are printed at the end, as many solvers print their progress
+fn run_bench(args: &Args, solvers: &[&Solver], samples: usize) -> ExitCode {
+    let mut code = ExitCode::SUCCESS;
+    let mut tables: Vec<Vec<Measure>> = Vec::new();
+    let skips = skips();
+    for solver in solvers {
+        if args.variant.is_none() {
+            if let Some(skip) = skips.iter().find(|skip| skip.matches(solver, &args.input_name())) {
+                eprintln!("{}: left out, {}", solver.name(), skip.reason);
+                continue;
+            }
+        }
+        let input = match args.read_input(solver) {
+            Ok(input) => input,
+            Err(msg) => {
+                eprintln!("{}: {msg}", solver.name());
+                code = ExitCode::FAILURE;
+                continue;
+            }
+        };
+        let measure = match panic::catch_unwind(|| bench::measure(solver, &input, samples)) {
+            Ok(measure) => measure,
+            Err(_) => {
+                eprintln!("{}: panicked", solver.name());
+                code = ExitCode::FAILURE;
+                continue;
+            }
+        };
+        match tables.last_mut() {
+            Some(table) if (table[0].solver.year, table[0].solver.day, table[0].solver.part) == (solver.year, solver.day, solver.part) => table.push(measure),
+            _ => tables.push(vec![measure]),
+        }
+    }
+
+    println!();
+    for table in tables {
+        bench::print_table(&table);
+        println!();
+    }
+    code
+}
@@ -0,0 +1,47 @@
+//! The `skip` lines of `answers.txt`: variants left out on some inputs, because they do not
+//! end or give a wrong answer there. Both the answers test and `aoc --bench` honour them.
+
+use crate::Solver;
+
+const MANIFEST: &str = include_str!("../answers.txt");
+
+pub struct Skip {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    /// `-` for the reference solution
+    pub variant: &'static str,
+    /// input file name, `*` for any input
+    pub input: &'static str,
+    pub reason: String,
+}
+
+impl Skip {
+    pub fn matches(&self, solver: &Solver, input: &str) -> bool {
+        (self.year, self.day, self.part) == (solver.year, solver.day, solver.part)
+            && (self.variant == solver.variant || self.variant == "-" && solver.variant.is_empty())
+            && (self.input == input || self.input == "*")
+    }
+}
+
+/// Every `skip` line of `answers.txt`, panics on a line that cannot be parsed.
+pub fn skips() -> Vec<Skip> {
+    let mut res = Vec::new();
+    for (n, line) in (1..).zip(MANIFEST.lines()) {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        if fields.first() != Some(&"skip") {
+            continue;
+        }
+        let bad_line = || panic!("answers.txt:{n}: cannot parse `{line}`");
+        let [_, year, day, part, variant, input, ref reason @ ..] = fields[..] else { bad_line() };
+        res.push(Skip {
+            year: year.parse().unwrap_or_else(|_| bad_line()),
+            day: day.parse().unwrap_or_else(|_| bad_line()),
+            part: part.parse().unwrap_or_else(|_| bad_line()),
+            variant,
+            input,
+            reason: reason.join(" "),
+        });
+    }
+    res
+}
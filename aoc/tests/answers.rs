@@ -0,0 +1,97 @@
+//! Runs every registered solver against the answers recorded in `answers.txt`.
+
+use std::panic;
+
+use aoc::skip::skips;
+use aoc::{Solver, SOLVERS};
+use aoc_common::{read_input, Answer};
+
+const MANIFEST: &str = include_str!("../answers.txt");
+
+struct Expected {
+    year: u16,
+    day: u8,
+    part: u8,
+    input: &'static str,
+    answer: Answer,
+    slow: bool,
+}
+
+// the expected answers, the `skip` lines are read by `aoc::skip`
+fn parse_manifest() -> Vec<Expected> {
+    let mut expected = Vec::new();
+
+    for (n, line) in (1..).zip(MANIFEST.lines()) {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') || line.starts_with("skip ") {
+            continue;
+        }
+        let bad_line = || panic!("answers.txt:{n}: cannot parse `{line}`");
+        let fields: Vec<&str> = line.split_whitespace().collect();
+
+        let (slow, fields) = match fields[..] {
+            [ref fields @ .., "slow"] => (true, fields),
+            ref fields => (false, fields),
+        };
+        let [year, day, part, input, answer] = fields[..] else { bad_line() };
+        let answer = match answer.parse::<i128>() {
+            Ok(n) => Answer::Number(n),
+            Err(_) => Answer::Text(answer.replace("\\n", "\n")),
+        };
+        expected.push(Expected {
+            year: year.parse().unwrap_or_else(|_| bad_line()),
+            day: day.parse().unwrap_or_else(|_| bad_line()),
+            part: part.parse().unwrap_or_else(|_| bad_line()),
+            input,
+            answer,
+            slow,
+        });
+    }
+    expected
+}
+
+fn check_answers(slow: bool) {
+    let expected = parse_manifest();
+    let skips = skips();
+    let mut failures = Vec::new();
+
+    for e in expected.iter().filter(|e| e.slow == slow) {
+        let solvers: Vec<&Solver> = SOLVERS
+            .iter()
+            .filter(|s| (s.year, s.day, s.part) == (e.year, e.day, e.part))
+            .filter(|s| !skips.iter().any(|skip| skip.matches(s, e.input)))
+            .collect();
+        if solvers.is_empty() {
+            failures.push(format!("{} day {} part {} ({}): no solver to check", e.year, e.day, e.part, e.input));
+        }
+
+        for solver in solvers {
+            let name = format!("{} ({})", solver.name(), e.input);
+            let input = match read_input(solver.dir(), e.input) {
+                Ok(input) => input,
+                Err(err) => {
+                    failures.push(format!("{name}: {err}"));
+                    continue;
+                }
+            };
+            match panic::catch_unwind(|| (solver.run)(&input)) {
+                Ok(answer) if answer == e.answer => {}
+                Ok(answer) => failures.push(format!("{name}: expected {}, got {answer}", e.answer)),
+                Err(_) => failures.push(format!("{name}: panicked")),
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "\n{}\n", failures.join("\n"));
+}
+
+#[test]
+fn recorded_answers() {
+    check_answers(false);
+}
+
+#[test]
+#[ignore = "slow in debug builds, run with `cargo test --release -p aoc -- --ignored`"]
+fn recorded_answers_slow() {
+    check_answers(true);
+}